@@ -0,0 +1,117 @@
+// escape.rs
+//
+// Copyright (C) 2026  Douglas P Lau
+//
+use criterion::{Criterion, criterion_group, criterion_main};
+use hatmil::{
+    Tree,
+    html::{Div, P, Table},
+};
+use std::hint::black_box;
+
+/// Make a multi-kilobyte text node, with occasional escaped characters
+fn text() -> String {
+    "Lorem ipsum dolor sit amet, consectetur adipiscing elit & sed do \
+    eiusmod tempor <incididunt> ut labore et dolore magna aliqua. "
+        .repeat(64)
+}
+
+/// Make a multi-kilobyte text node of English prose, with nothing to escape
+fn prose() -> String {
+    "It was the best of times, it was the worst of times, it was the age \
+    of wisdom, it was the age of foolishness, it was the epoch of belief. "
+        .repeat(64)
+}
+
+fn cdata(c: &mut Criterion) {
+    let text = text();
+    c.bench_function("cdata", |b| {
+        b.iter(|| {
+            let mut tree = Tree::new();
+            tree.root::<P>().cdata(black_box(&text));
+            String::from(tree)
+        })
+    });
+}
+
+fn attr(c: &mut Criterion) {
+    let text = text();
+    c.bench_function("attr", |b| {
+        b.iter(|| {
+            let mut tree = Tree::new();
+            tree.root::<P>().title(black_box(&text));
+            String::from(tree)
+        })
+    });
+}
+
+fn cdata_prose(c: &mut Criterion) {
+    let text = prose();
+    c.bench_function("cdata_prose", |b| {
+        b.iter(|| {
+            let mut tree = Tree::new();
+            tree.root::<P>().cdata(black_box(text.as_str()));
+            String::from(tree)
+        })
+    });
+}
+
+fn attr_prose(c: &mut Criterion) {
+    let text = prose();
+    c.bench_function("attr_prose", |b| {
+        b.iter(|| {
+            let mut tree = Tree::new();
+            tree.root::<P>().title(black_box(text.as_str()));
+            String::from(tree)
+        })
+    });
+}
+
+fn attr_heavy(c: &mut Criterion) {
+    c.bench_function("attr_heavy", |b| {
+        b.iter(|| {
+            let mut tree = Tree::new();
+            let mut div = tree.root::<Div>();
+            for i in 0..256 {
+                div.input()
+                    .id(black_box("field"))
+                    .class(black_box("form-control wide"))
+                    .name(black_box("field"))
+                    .r#type("text")
+                    .value(i)
+                    .required()
+                    .close();
+            }
+            String::from(tree)
+        })
+    });
+}
+
+fn numeric_table(c: &mut Criterion) {
+    c.bench_function("numeric_table", |b| {
+        b.iter(|| {
+            let mut tree = Tree::new();
+            let mut table = tree.root::<Table>();
+            let mut tbody = table.tbody();
+            for row in 0..100_u32 {
+                let mut tr = tbody.tr();
+                for col in 0..100_u32 {
+                    tr.td().cdata(black_box(row * 100 + col)).close();
+                }
+                tr.close();
+            }
+            String::from(tree)
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    cdata,
+    attr,
+    cdata_prose,
+    attr_prose,
+    attr_heavy,
+    numeric_table
+);
+criterion_main!(benches);
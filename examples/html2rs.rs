@@ -0,0 +1,15 @@
+// html2rs.rs
+//
+// Copyright (C) 2026  Douglas P Lau
+//
+//! Convert HTML into builder code
+//!
+//! Usage: `cargo run --example html2rs --features codegen < page.html`
+use std::io::{self, Read};
+
+fn main() -> io::Result<()> {
+    let mut html = String::new();
+    io::stdin().read_to_string(&mut html)?;
+    print!("{}", hatmil::codegen::generate(&html));
+    Ok(())
+}
@@ -0,0 +1,270 @@
+// ssg.rs
+//
+// Copyright (C) 2026  Douglas P Lau
+//
+//! Static site generator example
+//!
+//! Usage: `cargo run --example ssg [output directory]`
+use hatmil::Tree;
+use hatmil::html::{Body, Head};
+use hatmil::svg::Svg;
+use std::path::PathBuf;
+use std::{env, fs, io};
+
+/// Site pages
+const PAGES: &[Page] = &[
+    Page {
+        file: "index.html",
+        title: "Home",
+        kind: Kind::Index,
+    },
+    Page {
+        file: "article.html",
+        title: "Building HTML",
+        kind: Kind::Article,
+    },
+    Page {
+        file: "chart.html",
+        title: "Cat Chart",
+        kind: Kind::Chart,
+    },
+];
+
+/// Article sections (heading, paragraph)
+const SECTIONS: &[(&str, &str)] = &[
+    (
+        "Elements",
+        "Each element has methods for attributes & permitted children.",
+    ),
+    (
+        "Escaping",
+        "Text such as <script> is escaped automatically.",
+    ),
+];
+
+/// Example code block
+const CODE: &str = "let mut tree = Tree::new();\n\
+    tree.root::<Div>().cdata(\"Hello\");";
+
+/// Chart data (label, value)
+const DATA: &[(&str, u32)] =
+    &[("nori", 42), ("chashu", 27), ("tofu", 35), ("miso", 12)];
+
+/// Trusted footer, included verbatim
+const FOOTER: &str = "<small>&copy; 2026 Example Site</small>";
+
+/// Page kind
+enum Kind {
+    Index,
+    Article,
+    Chart,
+}
+
+/// Page definition
+struct Page {
+    file: &'static str,
+    title: &'static str,
+    kind: Kind,
+}
+
+/// Add document head
+fn head(head: &mut Head, title: &str) {
+    head.meta().charset("utf-8").close();
+    head.title_el().cdata(title).close();
+    head.link().rel("stylesheet").href("style.css").close();
+}
+
+/// Add navigation links
+fn nav(body: &mut Body) {
+    let mut nav = body.nav();
+    let mut ul = nav.ul();
+    for page in PAGES {
+        let mut li = ul.li();
+        li.a().href(page.file).cdata(page.title);
+        li.close();
+    }
+    nav.close();
+}
+
+/// Add index page content
+fn index(body: &mut Body) {
+    let mut main = body.main();
+    main.h1().cdata("Welcome").close();
+    main.p().cdata("This site was generated by hatmil.").close();
+    main.close();
+}
+
+/// Add article page content
+fn article(body: &mut Body) {
+    let mut article = body.article();
+    article.h1().cdata("Building HTML").close();
+    for (heading, text) in SECTIONS {
+        let mut section = article.section();
+        section.h2().cdata(*heading).close();
+        section.p().cdata(*text).close();
+        section.close();
+    }
+    let mut figure = article.figure();
+    figure
+        .img()
+        .src("tree.png")
+        .alt("A tree")
+        .width(320)
+        .close();
+    figure.figcaption().cdata("Figure 1: A tree").close();
+    figure.close();
+    let mut table = article.table();
+    table.caption().cdata("Methods").close();
+    let mut thead = table.thead();
+    let mut tr = thead.tr();
+    tr.th().cdata("Method").close();
+    tr.th().cdata("Purpose").close();
+    thead.close();
+    let mut tbody = table.tbody();
+    for (method, purpose) in [("cdata", "Add text"), ("raw", "Add trusted")] {
+        let mut tr = tbody.tr();
+        let mut td = tr.td();
+        td.code().cdata(method);
+        td.close();
+        tr.td().cdata(purpose).close();
+        tr.close();
+    }
+    tbody.close();
+    table.close();
+    article.pre().code().cdata(CODE).close();
+    article.close();
+}
+
+/// Add chart page content
+fn chart(body: &mut Body) {
+    body.h1().cdata("Cat Chart").close();
+    let mut svg = body.svg();
+    svg.width(240).height(120).view_box("0 0 240 120");
+    chart_bars(&mut svg);
+    svg.close();
+}
+
+/// Add chart bars
+fn chart_bars(svg: &mut Svg) {
+    for (i, (label, value)) in DATA.iter().enumerate() {
+        let x = 10 + i * 60;
+        let height = value * 2;
+        let y = 100 - height;
+        svg.rect()
+            .x(x)
+            .y(y)
+            .width(40)
+            .height(height)
+            .fill("steelblue")
+            .close();
+        svg.text().x(x + 20).y(115).cdata(*label).close();
+    }
+}
+
+/// Render one page
+fn render(page: &Page) -> String {
+    let mut tree = Tree::new();
+    let mut html = tree.html();
+    html.lang("en");
+    let mut hd = html.head();
+    head(&mut hd, page.title);
+    hd.close();
+    let mut body = html.body();
+    nav(&mut body);
+    match page.kind {
+        Kind::Index => index(&mut body),
+        Kind::Article => article(&mut body),
+        Kind::Chart => chart(&mut body),
+    }
+    let mut footer = body.footer();
+    // TRUSTED: static footer markup
+    footer.raw(FOOTER);
+    String::from(tree)
+}
+
+/// Render all pages of the site
+fn render_site() -> Vec<(&'static str, String)> {
+    PAGES.iter().map(|page| (page.file, render(page))).collect()
+}
+
+fn main() -> io::Result<()> {
+    let dir = PathBuf::from(env::args().nth(1).unwrap_or("site".into()));
+    fs::create_dir_all(&dir)?;
+    for (file, html) in render_site() {
+        let path = dir.join(file);
+        fs::write(&path, html)?;
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Void elements (no closing tag)
+    const VOID: &[&str] = &["br", "img", "input", "meta", "link"];
+
+    /// Check that all tags are balanced, returning the number of elements
+    fn check_balanced(html: &str) -> usize {
+        let html = html.strip_prefix("<!DOCTYPE html>").unwrap();
+        let mut stack = Vec::new();
+        let mut count = 0;
+        for part in html.split('<').skip(1) {
+            let (tag, _rest) = part.split_once('>').expect("unclosed tag");
+            let self_closing = tag.ends_with('/');
+            let tag = tag.trim_end_matches('/');
+            let name = tag.split_whitespace().next().unwrap();
+            if let Some(name) = name.strip_prefix('/') {
+                assert_eq!(stack.pop(), Some(name), "mismatched </{name}>");
+            } else if !self_closing && !VOID.contains(&name) {
+                stack.push(name);
+                count += 1;
+            } else {
+                count += 1;
+            }
+        }
+        assert!(stack.is_empty(), "unclosed: {stack:?}");
+        count
+    }
+
+    #[test]
+    fn site() {
+        let site = render_site();
+        assert_eq!(site.len(), PAGES.len());
+        for (file, html) in site {
+            assert!(!html.is_empty(), "{file}");
+            assert!(check_balanced(&html) > 10, "{file}");
+            assert!(html.contains("</head><body><nav>"), "{file}");
+            assert!(html.contains("</ul></nav>"), "{file}");
+            assert!(html.ends_with("</footer></body></html>"), "{file}");
+        }
+    }
+
+    #[test]
+    fn escaping() {
+        let html = render(&PAGES[1]);
+        assert!(html.contains("Text such as &lt;script&gt; is escaped"));
+        assert!(html.contains("attributes &amp; permitted children"));
+    }
+
+    #[test]
+    fn article() {
+        let html = render(&PAGES[1]);
+        assert!(
+            html.contains(
+                "<tr><td><code>cdata</code></td><td>Add text</td></tr>"
+            )
+        );
+        assert!(html.contains(
+            "<img src=\"tree.png\" alt=\"A tree\" width=\"320\"><figcaption>"
+        ));
+    }
+
+    #[test]
+    fn chart() {
+        let html = render(&PAGES[2]);
+        assert!(html.contains("<rect x=\"10\" y=\"16\" width=\"40\" height=\"84\" fill=\"steelblue\" />"));
+        assert!(html.contains("<text x=\"30\" y=\"115\">nori</text>"));
+    }
+}
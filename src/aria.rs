@@ -0,0 +1,41 @@
+// aria.rs
+//
+// Copyright (C) 2026  Douglas P Lau
+//
+use crate::value::Value;
+
+/// [ARIA] attribute methods, implemented for all HTML elements
+///
+/// ```rust
+/// use hatmil::{AriaAttrs, Tree, html::Button};
+///
+/// let mut tree = Tree::new();
+/// tree.root::<Button>()
+///     .aria_label("Close")
+///     .aria_expanded(false)
+///     .cdata("X");
+/// assert_eq!(
+///     String::from(tree),
+///     "<button aria-label=\"Close\" aria-expanded=\"false\">X</button>",
+/// );
+/// ```
+///
+/// [ARIA]: https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA
+pub trait AriaAttrs {
+    /// Add an ARIA attribute
+    #[doc(hidden)]
+    fn aria_attr(&mut self, attr: &'static str, val: Value<'_>);
+
+    /// Add [role] attribute (same as `role`)
+    ///
+    /// [role]: https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Reference/Roles
+    fn aria_role<'a, V>(&mut self, val: V) -> &mut Self
+    where
+        V: Into<Value<'a>>,
+    {
+        self.aria_attr("role", val.into());
+        self
+    }
+
+    aria_attributes!();
+}
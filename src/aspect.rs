@@ -0,0 +1,199 @@
+// aspect.rs
+//
+// Copyright (C) 2026  Douglas P Lau
+//
+use crate::value::Value;
+use std::fmt;
+
+/// Alignment of a view box within a viewport
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Align {
+    /// `xMinYMin`
+    XMinYMin,
+    /// `xMidYMin`
+    XMidYMin,
+    /// `xMaxYMin`
+    XMaxYMin,
+    /// `xMinYMid`
+    XMinYMid,
+    /// `xMidYMid` (default)
+    XMidYMid,
+    /// `xMaxYMid`
+    XMaxYMid,
+    /// `xMinYMax`
+    XMinYMax,
+    /// `xMidYMax`
+    XMidYMax,
+    /// `xMaxYMax`
+    XMaxYMax,
+}
+
+/// Scaling of a view box within a viewport
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MeetOrSlice {
+    /// Scale to fit entirely within the viewport (default)
+    Meet,
+    /// Scale to cover the entire viewport
+    Slice,
+}
+
+/// SVG [preserveAspectRatio] attribute
+///
+/// ```rust
+/// use hatmil::{Align, MeetOrSlice, PreserveAspectRatio, Tree, svg::Svg};
+///
+/// let mut tree = Tree::new();
+/// tree.root::<Svg>().preserve_aspect_ratio(
+///     PreserveAspectRatio::new(Align::XMinYMid).with(MeetOrSlice::Slice),
+/// );
+/// assert_eq!(
+///     String::from(tree),
+///     "<svg preserveAspectRatio=\"xMinYMid slice\" />",
+/// );
+/// ```
+///
+/// [preserveAspectRatio]: https://developer.mozilla.org/en-US/docs/Web/SVG/Reference/Attribute/preserveAspectRatio
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PreserveAspectRatio {
+    /// Alignment (`None` for non-uniform scaling)
+    align: Option<Align>,
+    /// Meet or slice
+    meet_or_slice: Option<MeetOrSlice>,
+}
+
+impl Align {
+    /// Get attribute token
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Align::XMinYMin => "xMinYMin",
+            Align::XMidYMin => "xMidYMin",
+            Align::XMaxYMin => "xMaxYMin",
+            Align::XMinYMid => "xMinYMid",
+            Align::XMidYMid => "xMidYMid",
+            Align::XMaxYMid => "xMaxYMid",
+            Align::XMinYMax => "xMinYMax",
+            Align::XMidYMax => "xMidYMax",
+            Align::XMaxYMax => "xMaxYMax",
+        }
+    }
+}
+
+impl fmt::Display for Align {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl MeetOrSlice {
+    /// Get attribute token
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MeetOrSlice::Meet => "meet",
+            MeetOrSlice::Slice => "slice",
+        }
+    }
+}
+
+impl fmt::Display for MeetOrSlice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl fmt::Display for PreserveAspectRatio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.align {
+            Some(align) => write!(f, "{align}")?,
+            None => write!(f, "none")?,
+        }
+        if let Some(mos) = self.meet_or_slice {
+            write!(f, " {mos}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Align> for PreserveAspectRatio {
+    fn from(align: Align) -> Self {
+        PreserveAspectRatio::new(align)
+    }
+}
+
+impl From<PreserveAspectRatio> for Value<'_> {
+    fn from(par: PreserveAspectRatio) -> Self {
+        Value::from(par.to_string())
+    }
+}
+
+impl From<Align> for Value<'_> {
+    fn from(align: Align) -> Self {
+        Value::from(align.as_str())
+    }
+}
+
+impl PreserveAspectRatio {
+    /// Create with uniform scaling alignment
+    pub fn new(align: Align) -> Self {
+        PreserveAspectRatio {
+            align: Some(align),
+            meet_or_slice: None,
+        }
+    }
+
+    /// Create with non-uniform scaling (`none`)
+    pub fn none() -> Self {
+        PreserveAspectRatio {
+            align: None,
+            meet_or_slice: None,
+        }
+    }
+
+    /// Set meet or slice scaling
+    pub fn with(mut self, meet_or_slice: MeetOrSlice) -> Self {
+        self.meet_or_slice = Some(meet_or_slice);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Tree;
+    use crate::svg::Svg;
+
+    #[test]
+    fn tokens() {
+        assert_eq!(PreserveAspectRatio::none().to_string(), "none");
+        assert_eq!(
+            PreserveAspectRatio::new(Align::XMaxYMin).to_string(),
+            "xMaxYMin"
+        );
+        assert_eq!(
+            PreserveAspectRatio::new(Align::XMidYMax)
+                .with(MeetOrSlice::Meet)
+                .to_string(),
+            "xMidYMax meet"
+        );
+    }
+
+    #[test]
+    fn svg() {
+        let mut tree = Tree::new();
+        let mut svg = tree.root::<Svg>();
+        svg.preserve_aspect_ratio(PreserveAspectRatio::none());
+        svg.image()
+            .preserve_aspect_ratio(
+                PreserveAspectRatio::new(Align::XMinYMin)
+                    .with(MeetOrSlice::Slice),
+            )
+            .close();
+        let mut filter = svg.filter();
+        filter.fe_image().preserve_aspect_ratio(Align::XMaxYMax);
+        assert_eq!(
+            String::from(tree),
+            "<svg preserveAspectRatio=\"none\">\
+            <image preserveAspectRatio=\"xMinYMin slice\" />\
+            <filter><feImage preserveAspectRatio=\"xMaxYMax\" /></filter></svg>"
+        );
+    }
+}
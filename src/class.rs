@@ -0,0 +1,117 @@
+// class.rs
+//
+// Copyright (C) 2026  Douglas P Lau
+//
+use crate::value::Value;
+use std::fmt;
+
+/// [class] attribute builder
+///
+/// ```rust
+/// use hatmil::{ClassList, Tree, html::Button};
+///
+/// let active = true;
+/// let mut classes = ClassList::new();
+/// classes.add("btn").add_if("active", active);
+/// let mut tree = Tree::new();
+/// tree.root::<Button>().class(classes).cdata("Press");
+/// assert_eq!(
+///     String::from(tree),
+///     "<button class=\"btn active\">Press</button>",
+/// );
+/// ```
+///
+/// [class]: https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/class
+#[derive(Clone, Debug, Default)]
+pub struct ClassList {
+    /// Space-separated class names
+    classes: String,
+}
+
+impl fmt::Display for ClassList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.classes)
+    }
+}
+
+impl From<ClassList> for String {
+    fn from(classes: ClassList) -> Self {
+        // zero-copy alternative to fmt::Display
+        classes.classes
+    }
+}
+
+impl From<ClassList> for Value<'_> {
+    fn from(classes: ClassList) -> Self {
+        Value::from(classes.classes)
+    }
+}
+
+impl<'a> From<&'a ClassList> for Value<'a> {
+    fn from(classes: &'a ClassList) -> Self {
+        Value::from(classes.classes.as_str())
+    }
+}
+
+impl ClassList {
+    /// Create a new class list builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a class name
+    ///
+    /// Empty names are skipped.
+    pub fn add(&mut self, cls: &str) -> &mut Self {
+        let cls = cls.trim();
+        if !cls.is_empty() {
+            if !self.classes.is_empty() {
+                self.classes.push(' ');
+            }
+            self.classes.push_str(cls);
+        }
+        self
+    }
+
+    /// Add a class name if a condition is true
+    pub fn add_if(&mut self, cls: &str, cond: bool) -> &mut Self {
+        if cond {
+            self.add(cls);
+        }
+        self
+    }
+
+    /// Check if no classes have been added
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Tree;
+    use crate::html::Button;
+
+    fn button(active: bool) -> String {
+        let mut classes = ClassList::new();
+        classes.add("btn").add("").add_if("active", active);
+        let mut tree = Tree::new();
+        tree.root::<Button>().class(&classes);
+        String::from(tree)
+    }
+
+    #[test]
+    fn add_if() {
+        assert_eq!(button(true), "<button class=\"btn active\"></button>");
+        assert_eq!(button(false), "<button class=\"btn\"></button>");
+    }
+
+    #[test]
+    fn empty() {
+        let mut classes = ClassList::new();
+        classes.add(" ").add_if("hidden", false);
+        assert!(classes.is_empty());
+        assert_eq!(classes.to_string(), "");
+    }
+}
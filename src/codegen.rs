@@ -0,0 +1,810 @@
+// codegen.rs
+//
+// Copyright (C) 2026  Douglas P Lau
+//
+//! Code generator -- convert HTML into builder code
+//!
+//! This is a migration tool for existing HTML templates.  Markup is parsed
+//! permissively, and Rust source using the [Tree] builder API is generated.
+//!
+//! - Unknown elements and undecodable text fall back to `raw`
+//! - Unknown attributes are flagged with a `FIXME` comment and skipped
+//! - Whitespace-only text between tags is dropped
+//!
+//! The generated code may need some manual fixes, since permitted content is
+//! not checked.
+//!
+//! [Tree]: crate::Tree
+use std::fmt::Write;
+
+/// HTML void elements
+const VOID: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta",
+    "source", "track", "wbr",
+];
+
+/// Element methods, collected from element macros
+pub(crate) struct ElemInfo {
+    /// SVG element
+    svg: bool,
+    /// Element tag
+    tag: &'static str,
+    /// Element struct
+    st: &'static str,
+    /// Child element methods
+    children: Vec<ChildMethod>,
+    /// Attribute methods (name, method, kind)
+    attrs: Vec<(&'static str, &'static str, AttrKind)>,
+    /// Custom element children allowed
+    custom: bool,
+}
+
+/// Attribute method kind
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum AttrKind {
+    /// Value parameter
+    Value,
+    /// No parameter (Boolean attribute)
+    Boolean,
+    /// `bool` parameter, for `true` / `false` values
+    TrueFalse,
+}
+
+/// Function to get element methods
+type ElemFn = fn() -> ElemInfo;
+
+/// Child element method
+struct ChildMethod {
+    /// Method name
+    meth: &'static str,
+    /// SVG child element
+    svg: bool,
+    /// Child element tag
+    tag: &'static str,
+    /// Child element methods
+    info: ElemFn,
+}
+
+/// Elements reachable from root elements
+struct Elems {
+    /// Element info
+    elems: Vec<ElemInfo>,
+    /// Custom element info
+    custom: ElemInfo,
+}
+
+/// Parsed HTML node
+enum Node<'a> {
+    /// Document type declaration (full markup)
+    Doctype(&'a str),
+    /// Comment (inner text)
+    Comment(&'a str),
+    /// Text (not decoded)
+    Text(&'a str),
+    /// Element
+    Elem(Elem<'a>),
+}
+
+/// Parsed HTML element
+struct Elem<'a> {
+    /// Element tag
+    tag: &'a str,
+    /// Attributes (name, value)
+    attrs: Vec<(&'a str, Option<&'a str>)>,
+    /// Child nodes
+    children: Vec<Node<'a>>,
+    /// Start position of markup
+    start: usize,
+    /// Outer markup
+    src: &'a str,
+}
+
+/// Code generator
+struct Generator<'e> {
+    /// Element info
+    elems: &'e Elems,
+    /// Generated code
+    code: String,
+    /// Indent level
+    indent: usize,
+}
+
+/// Generate builder code from HTML
+///
+/// The code creates a `Tree` named `tree`, and expects `Tree`, `AriaAttrs`,
+/// `html` and `svg` from the crate to be in scope.
+///
+/// ```rust
+/// let code = hatmil::codegen::generate("<p class=\"note\">Hello</p>");
+/// assert_eq!(
+///     code,
+///     "let mut tree = Tree::new();\n\
+///     tree.root::<html::P>().class(\"note\").cdata(\"Hello\").close();\n",
+/// );
+/// ```
+pub fn generate(html: &str) -> String {
+    let elems = Elems::new();
+    let mut generator = Generator {
+        elems: &elems,
+        code: String::new(),
+        indent: 0,
+    };
+    generator.line("let mut tree = Tree::new();");
+    generator.top(&parse(html));
+    generator.code
+}
+
+/// Generate a builder function from HTML
+///
+/// The function creates and returns a `Tree`, and expects `Tree`,
+/// `AriaAttrs`, `html` and `svg` from the crate to be in scope.
+///
+/// ```rust
+/// let code = hatmil::codegen::generate_fn("note", "<p>Hello</p>");
+/// assert_eq!(
+///     code,
+///     "fn note() -> Tree {\n    \
+///     let mut tree = Tree::new();\n    \
+///     tree.root::<html::P>().cdata(\"Hello\").close();\n    \
+///     tree\n\
+///     }\n",
+/// );
+/// ```
+pub fn generate_fn(name: &str, html: &str) -> String {
+    let elems = Elems::new();
+    let mut generator = Generator {
+        elems: &elems,
+        code: String::new(),
+        indent: 0,
+    };
+    generator.line(&format!("fn {name}() -> Tree {{"));
+    generator.indent += 1;
+    generator.line("let mut tree = Tree::new();");
+    generator.top(&parse(html));
+    generator.line("tree");
+    generator.indent -= 1;
+    generator.line("}");
+    generator.code
+}
+
+/// Get a string literal
+fn lit(s: &str) -> String {
+    format!("{s:?}")
+}
+
+/// Check if a tag is a custom element name
+fn is_custom(tag: &str) -> bool {
+    tag.starts_with(|c: char| c.is_ascii_lowercase())
+        && tag.contains('-')
+        && tag
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// Decode character references in text
+fn decode(text: &str) -> Option<String> {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(i) = rest.find('&') {
+        out.push_str(&rest[..i]);
+        rest = &rest[i + 1..];
+        let end = rest.find(';')?;
+        let c = match &rest[..end] {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            "nbsp" => '\u{a0}',
+            ent => {
+                let num = ent.strip_prefix('#')?;
+                let n = match num.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => num.parse().ok()?,
+                };
+                char::from_u32(n)?
+            }
+        };
+        out.push(c);
+        rest = &rest[end + 1..];
+    }
+    out.push_str(rest);
+    Some(out)
+}
+
+impl ElemInfo {
+    /// Create element info
+    pub(crate) fn new(svg: bool, tag: &'static str, st: &'static str) -> Self {
+        ElemInfo {
+            svg,
+            tag,
+            st,
+            children: Vec::new(),
+            attrs: Vec::new(),
+            custom: false,
+        }
+    }
+
+    /// Add a child element method
+    pub(crate) fn child(
+        &mut self,
+        meth: &'static str,
+        svg: bool,
+        tag: &'static str,
+        info: ElemFn,
+    ) {
+        if !self.children.iter().any(|c| c.meth == meth) {
+            self.children.push(ChildMethod {
+                meth,
+                svg,
+                tag,
+                info,
+            });
+        }
+    }
+
+    /// Add an attribute method
+    pub(crate) fn attr(
+        &mut self,
+        name: &'static str,
+        meth: &'static str,
+        kind: AttrKind,
+    ) {
+        if !self.attrs.iter().any(|(n, _m, _k)| *n == name) {
+            self.attrs.push((name, meth, kind));
+        }
+    }
+
+    /// Allow custom element children
+    pub(crate) fn custom(&mut self) {
+        self.custom = true;
+    }
+
+    /// Get the struct path
+    fn path(&self) -> String {
+        let module = if self.svg { "svg" } else { "html" };
+        format!("{module}::{}", self.st)
+    }
+
+    /// Get the method to add a child element
+    fn child_method(&self, child: &Self) -> Option<&'static str> {
+        self.children
+            .iter()
+            .find(|c| c.svg == child.svg && c.tag == child.tag)
+            .map(|c| c.meth)
+    }
+
+    /// Get the method for an attribute (method, kind)
+    fn attr_method(&self, name: &str) -> Option<(&'static str, AttrKind)> {
+        let find = |name: &str| {
+            self.attrs
+                .iter()
+                .find(|(n, _meth, _k)| *n == name)
+                .map(|(_n, meth, k)| (*meth, *k))
+        };
+        find(name).or_else(|| find(&name.to_ascii_lowercase()))
+    }
+}
+
+impl Elems {
+    /// Collect elements reachable from root elements
+    fn new() -> Self {
+        let custom = crate::html::Custom::codegen();
+        let mut elems: Vec<ElemInfo> = Vec::new();
+        let mut pending: Vec<(bool, &str, ElemFn)> = vec![
+            (false, "html", crate::html::Html::codegen),
+            (true, "svg", crate::svg::Svg::codegen),
+            // not a child of any element
+            (false, "col", crate::html::Col::codegen),
+        ];
+        pending.extend(custom.children.iter().map(|c| (c.svg, c.tag, c.info)));
+        while let Some((svg, tag, info)) = pending.pop() {
+            if elems.iter().any(|e| e.svg == svg && e.tag == tag) {
+                continue;
+            }
+            let info = info();
+            for c in &info.children {
+                if !elems.iter().any(|e| e.svg == c.svg && e.tag == c.tag) {
+                    pending.push((c.svg, c.tag, c.info));
+                }
+            }
+            elems.push(info);
+        }
+        Elems { elems, custom }
+    }
+
+    /// Find an element by tag
+    fn find(&self, tag: &str, svg: bool) -> Option<&ElemInfo> {
+        let tag = if svg {
+            tag.into()
+        } else {
+            tag.to_ascii_lowercase()
+        };
+        let find =
+            |svg| self.elems.iter().find(|e| e.svg == svg && e.tag == tag);
+        // svg is the only SVG element allowed in HTML content
+        find(svg).or_else(|| find(true).filter(|_| !svg && tag == "svg"))
+    }
+}
+
+/// Parse HTML permissively
+fn parse(html: &str) -> Vec<Node<'_>> {
+    let mut nodes = Vec::new();
+    let mut stack: Vec<Elem> = Vec::new();
+    let mut pos = 0;
+    while pos < html.len() {
+        let rest = &html[pos..];
+        let (node, len) = if let Some(com) = rest.strip_prefix("<!--")
+            && let Some(end) = com.find("-->")
+        {
+            (Some(Node::Comment(&com[..end])), end + 7)
+        } else if rest.starts_with("<!")
+            && let Some(end) = rest.find('>')
+        {
+            (Some(Node::Doctype(&rest[..=end])), end + 1)
+        } else if let Some(close) = rest.strip_prefix("</")
+            && let Some(end) = close.find('>')
+        {
+            let tag = close[..end].trim();
+            let len = end + 3;
+            if let Some(i) = stack.iter().rposition(|e| e.tag == tag) {
+                while stack.len() > i {
+                    let end =
+                        if stack.len() == i + 1 { pos + len } else { pos };
+                    finish(html, &mut stack, &mut nodes, end);
+                }
+            }
+            (None, len)
+        } else if let Some((mut elem, len)) = start_tag(rest) {
+            elem.start = pos;
+            let svg = elem.tag == "svg" || stack.iter().any(|e| e.tag == "svg");
+            if elem.src.ends_with("/>")
+                || (!svg && VOID.contains(&&*elem.tag.to_ascii_lowercase()))
+            {
+                (Some(Node::Elem(elem)), len)
+            } else if !svg && matches!(elem.tag, "script" | "style") {
+                let text = &rest[len..];
+                let close = format!("</{}", elem.tag);
+                let end = text.find(&close).unwrap_or(text.len());
+                let gt = text[end..].find('>').map_or(end, |gt| end + gt + 1);
+                if end > 0 {
+                    elem.children.push(Node::Text(&text[..end]));
+                }
+                elem.src = &rest[..len + gt];
+                (Some(Node::Elem(elem)), len + gt)
+            } else {
+                stack.push(elem);
+                (None, len)
+            }
+        } else {
+            let end = rest[1..].find('<').map_or(rest.len(), |e| e + 1);
+            (Some(Node::Text(&rest[..end])), end)
+        };
+        if let Some(node) = node {
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(node),
+                None => nodes.push(node),
+            }
+        }
+        pos += len;
+    }
+    while !stack.is_empty() {
+        finish(html, &mut stack, &mut nodes, html.len());
+    }
+    nodes
+}
+
+/// Finish the innermost open element
+fn finish<'a>(
+    html: &'a str,
+    stack: &mut Vec<Elem<'a>>,
+    nodes: &mut Vec<Node<'a>>,
+    end: usize,
+) {
+    if let Some(mut elem) = stack.pop() {
+        elem.src = &html[elem.start..end];
+        let node = Node::Elem(elem);
+        match stack.last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => nodes.push(node),
+        }
+    }
+}
+
+/// Parse a start tag, returning the element and markup length
+fn start_tag(rest: &str) -> Option<(Elem<'_>, usize)> {
+    let tag_end = |c: char| c.is_ascii_whitespace() || c == '/' || c == '>';
+    let name = rest.strip_prefix('<')?;
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let end = name.find(tag_end)?;
+    let tag = &name[..end];
+    let mut attrs = Vec::new();
+    let mut pos = end + 1;
+    loop {
+        let s = rest[pos..].trim_start();
+        pos = rest.len() - s.len();
+        if s.starts_with('>') || s.starts_with("/>") {
+            let len = pos + s.find('>')? + 1;
+            let elem = Elem {
+                tag,
+                attrs,
+                children: Vec::new(),
+                start: 0,
+                src: &rest[..len],
+            };
+            return Some((elem, len));
+        }
+        let end = s.find(|c: char| tag_end(c) || c == '=')?;
+        if end == 0 {
+            // stray slash
+            pos += 1;
+            continue;
+        }
+        let name = &s[..end];
+        let s = s[end..].trim_start();
+        let Some(val) = s.strip_prefix('=') else {
+            attrs.push((name, None));
+            pos = rest.len() - s.len();
+            continue;
+        };
+        let val = val.trim_start();
+        let (value, len) = match val.chars().next()? {
+            q @ ('"' | '\'') => {
+                let end = val[1..].find(q)?;
+                (&val[1..=end], end + 2)
+            }
+            _ => {
+                let end =
+                    val.find(|c: char| c.is_ascii_whitespace() || c == '>')?;
+                (&val[..end], end)
+            }
+        };
+        attrs.push((name, Some(value)));
+        pos = rest.len() - val.len() + len;
+    }
+}
+
+impl<'e> Generator<'e> {
+    /// Add a line of code
+    fn line(&mut self, line: &str) {
+        for _ in 0..self.indent {
+            self.code.push_str("    ");
+        }
+        self.code.push_str(line);
+        self.code.push('\n');
+    }
+
+    /// Generate code for top-level nodes
+    fn top(&mut self, nodes: &[Node]) {
+        let mut nodes = nodes.iter().filter(|n| !is_blank(n)).peekable();
+        if let Some(Node::Doctype(doctype)) = nodes.peek()
+            && doctype.eq_ignore_ascii_case("<!DOCTYPE html>")
+        {
+            nodes.next();
+            if let Some(Node::Elem(html)) = nodes.peek()
+                && let Some(info) = self.elems.find(html.tag, false)
+                && info.st == "Html"
+            {
+                nodes.next();
+                self.block("html", "tree.html()", html, info);
+            } else {
+                self.line(&format!("tree.raw({});", lit(doctype)));
+            }
+        }
+        for node in nodes {
+            match node {
+                Node::Elem(elem) => self.root(elem),
+                _ => self.content("tree", node),
+            }
+        }
+    }
+
+    /// Generate code for a root element
+    fn root(&mut self, elem: &Elem) {
+        let elems = self.elems;
+        if let Some(info) = elems.find(elem.tag, false) {
+            let call = format!("tree.root::<{}>()", info.path());
+            self.elem(&call, elem, info);
+        } else if is_custom(elem.tag) {
+            let call = format!("tree.custom({})", lit(elem.tag));
+            self.elem(&call, elem, &elems.custom);
+        } else {
+            self.unknown("tree", elem);
+        }
+    }
+
+    /// Generate code for a child node
+    fn child(&mut self, var: &str, parent: &ElemInfo, node: &Node) {
+        let Node::Elem(elem) = node else {
+            self.content(var, node);
+            return;
+        };
+        let elems = self.elems;
+        let child = elems.find(elem.tag, parent.svg);
+        if let Some(info) = child
+            && let Some(meth) = parent.child_method(info)
+        {
+            let call = format!("{var}.{meth}()");
+            self.elem(&call, elem, info);
+        } else if parent.custom && is_custom(elem.tag) {
+            let call = format!("{var}.custom({})", lit(elem.tag));
+            self.elem(&call, elem, &elems.custom);
+        } else {
+            self.unknown(var, elem);
+        }
+    }
+
+    /// Generate code for text, comment or doctype content
+    fn content(&mut self, var: &str, node: &Node) {
+        match node {
+            Node::Text(text) if text.trim().is_empty() => (),
+            Node::Text(text) => match decode(text) {
+                Some(text) if var != "tree" => {
+                    self.line(&format!("{var}.cdata({});", lit(&text)));
+                }
+                _ => self.line(&format!("{var}.raw({});", lit(text))),
+            },
+            Node::Comment(com) if !com.contains(['-', '<', '>']) => {
+                self.line(&format!("{var}.comment({});", lit(com)));
+            }
+            Node::Comment(com) => {
+                self.line(&format!(
+                    "{var}.raw({});",
+                    lit(&format!("<!--{com}-->"))
+                ));
+            }
+            Node::Doctype(doctype) => {
+                self.line(&format!("{var}.raw({});", lit(doctype)));
+            }
+            Node::Elem(elem) => self.unknown(var, elem),
+        }
+    }
+
+    /// Generate raw code for an unknown element
+    fn unknown(&mut self, var: &str, elem: &Elem) {
+        self.line(&format!("// FIXME: unknown element <{}>", elem.tag));
+        self.line(&format!("{var}.raw({});", lit(elem.src)));
+    }
+
+    /// Generate code for an element
+    fn elem(&mut self, call: &str, elem: &Elem, info: &ElemInfo) {
+        let children: Vec<_> =
+            elem.children.iter().filter(|n| !is_blank(n)).collect();
+        let text = match &children[..] {
+            [] => Some(None),
+            [Node::Text(text)] => decode(text).map(Some),
+            _ => None,
+        };
+        let raw_text = !info.svg && matches!(info.st, "Script" | "Style");
+        match text {
+            Some(Some(text)) if raw_text && text.contains(['&', '<', '>']) => {
+                self.unknown_text(call, elem);
+            }
+            Some(text) => {
+                let attrs = self.attrs(elem, info);
+                let cdata = text
+                    .map_or(String::new(), |t| format!(".cdata({})", lit(&t)));
+                self.line(&format!("{call}{attrs}{cdata}.close();"));
+            }
+            None => {
+                let var = var_name(call, elem.tag);
+                self.block(&var, call, elem, info);
+            }
+        }
+    }
+
+    /// Generate code for script or style with raw text
+    fn unknown_text(&mut self, call: &str, elem: &Elem) {
+        let var = call.split('.').next().unwrap_or("tree").to_string();
+        self.line(&format!("// FIXME: raw text in <{}>", elem.tag));
+        self.line(&format!("{var}.raw({});", lit(elem.src)));
+    }
+
+    /// Generate a block for an element with children
+    fn block(&mut self, var: &str, call: &str, elem: &Elem, info: &ElemInfo) {
+        self.line("{");
+        self.indent += 1;
+        self.line(&format!("let mut {var} = {call};"));
+        let attrs = self.attrs(elem, info);
+        if !attrs.is_empty() {
+            self.line(&format!("{var}{attrs};"));
+        }
+        for node in &elem.children {
+            self.child(var, info, node);
+        }
+        self.line(&format!("{var}.close();"));
+        self.indent -= 1;
+        self.line("}");
+    }
+
+    /// Generate attribute method calls, flagging unknown attributes
+    fn attrs(&mut self, elem: &Elem, info: &ElemInfo) -> String {
+        let mut calls = String::new();
+        for (name, val) in &elem.attrs {
+            let val = val.map(|v| decode(v).unwrap_or_else(|| v.to_string()));
+            if let Some(data) = name.strip_prefix("data-") {
+                let val = val.unwrap_or_default();
+                write!(calls, ".data_({}, {})", lit(data), lit(&val)).unwrap();
+                continue;
+            }
+            match (info.attr_method(name), val.as_deref()) {
+                (Some((meth, AttrKind::Boolean)), _) => {
+                    write!(calls, ".{meth}()").unwrap()
+                }
+                (Some((meth, AttrKind::Value)), val) => {
+                    let val = val.unwrap_or_default();
+                    write!(calls, ".{meth}({})", lit(val)).unwrap();
+                }
+                (
+                    Some((meth, AttrKind::TrueFalse)),
+                    Some(val @ ("true" | "false")),
+                ) => write!(calls, ".{meth}({val})").unwrap(),
+                (Some((_meth, AttrKind::TrueFalse)), val) => {
+                    self.line(&format!(
+                        "// FIXME: invalid value {val:?} for {name} on <{}>",
+                        elem.tag
+                    ))
+                }
+                (None, _) => self.line(&format!(
+                    "// FIXME: unknown attribute {name} on <{}>",
+                    elem.tag
+                )),
+            }
+        }
+        calls
+    }
+}
+
+/// Check if a node is whitespace-only text
+fn is_blank(node: &Node) -> bool {
+    matches!(node, Node::Text(text) if text.trim().is_empty())
+}
+
+/// Get a variable name for an element
+fn var_name(call: &str, tag: &str) -> String {
+    let name = match call.rsplit_once('.') {
+        Some((_, meth))
+            if !meth.starts_with("root") && !meth.starts_with("custom") =>
+        {
+            meth.trim_end_matches("()")
+                .trim_start_matches("r#")
+                .trim_end_matches("_el")
+                .to_string()
+        }
+        _ => tag.to_ascii_lowercase().replace('-', "_"),
+    };
+    match name.as_str() {
+        "use" | "loop" | "type" | "match" | "mod" => format!("{name}_"),
+        _ => name,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{AriaAttrs, Tree, html};
+
+    /// HTML fixture
+    const FIXTURE: &str = include_str!("../tests/fixtures/card.html");
+
+    // Code generated from fixture
+    include!("../tests/fixtures/card.rs");
+
+    /// Normalize HTML, removing whitespace between tags
+    fn normalize(html: &str) -> String {
+        html.lines().map(str::trim).collect()
+    }
+
+    #[test]
+    fn fixture() {
+        assert_eq!(
+            generate_fn("card", FIXTURE),
+            include_str!("../tests/fixtures/card.rs"),
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        assert_eq!(String::from(card()), normalize(FIXTURE));
+    }
+
+    #[test]
+    fn namespaces() {
+        assert_eq!(
+            generate(
+                "<p><a href=\"x\" systemLanguage=\"en\"></a>\
+                <svg><a href=\"y\" systemLanguage=\"en\"></a></svg></p>"
+            ),
+            r#"let mut tree = Tree::new();
+{
+    let mut p = tree.root::<html::P>();
+    // FIXME: unknown attribute systemLanguage on <a>
+    p.a().href("x").close();
+    {
+        let mut svg = p.svg();
+        svg.a().href("y").system_language("en").close();
+        svg.close();
+    }
+    p.close();
+}
+"#
+        );
+    }
+
+    #[test]
+    fn elems() {
+        let elems = Elems::new();
+        for (tag, svg, path) in [
+            ("a", false, "html::A"),
+            ("a", true, "svg::A"),
+            ("col", false, "html::Col"),
+            ("TITLE", false, "html::Title"),
+            ("title", true, "svg::Title"),
+            ("svg", false, "svg::Svg"),
+            ("feGaussianBlur", true, "svg::FeGaussianBlur"),
+        ] {
+            assert_eq!(elems.find(tag, svg).unwrap().path(), path);
+        }
+        assert!(elems.find("blink", false).is_none());
+    }
+
+    #[test]
+    fn fallback() {
+        let code = generate(
+            "<body onbogus=\"go()\"><blink>x</blink><my-el>y</my-el>\
+            <script>a < b</script></body>",
+        );
+        assert_eq!(
+            code,
+            r#"let mut tree = Tree::new();
+{
+    let mut body = tree.root::<html::Body>();
+    // FIXME: unknown attribute onbogus on <body>
+    // FIXME: unknown element <blink>
+    body.raw("<blink>x</blink>");
+    body.custom("my-el").cdata("y").close();
+    // FIXME: raw text in <script>
+    body.raw("<script>a < b</script>");
+    body.close();
+}
+"#
+        );
+    }
+
+    #[test]
+    fn event_attrs() {
+        assert_eq!(
+            generate("<button onclick=\"go()\">Go</button>"),
+            "let mut tree = Tree::new();\n\
+            tree.root::<html::Button>().onclick(\"go()\").cdata(\"Go\").close();\n",
+        );
+    }
+
+    #[test]
+    fn aria_attrs() {
+        assert_eq!(
+            generate(
+                "<div role=\"tab\" aria-label=\"Tab\" aria-hidden=\"true\">\
+                <p aria-expanded=\"maybe\"></p></div>"
+            ),
+            r#"let mut tree = Tree::new();
+{
+    let mut div = tree.root::<html::Div>();
+    div.role("tab").aria_label("Tab").aria_hidden(true);
+    // FIXME: invalid value Some("maybe") for aria-expanded on <p>
+    div.p().close();
+    div.close();
+}
+"#
+        );
+    }
+
+    #[test]
+    fn decoding() {
+        assert_eq!(decode("a &lt;b&gt; &#65;&#x42;").unwrap(), "a <b> AB");
+        assert_eq!(decode("&bogus;"), None);
+        assert_eq!(decode("a & b"), None);
+    }
+}
@@ -0,0 +1,104 @@
+// color.rs
+//
+// Copyright (C) 2026  Douglas P Lau
+//
+use crate::value::Value;
+use std::fmt;
+
+/// SVG color or paint, for `fill`, `stroke`, `stop-color` or `flood-color`
+///
+/// ```rust
+/// use hatmil::{SvgColor, Tree, svg::Circle};
+///
+/// let mut tree = Tree::new();
+/// tree.root::<Circle>()
+///     .fill(SvgColor::Rgb(255, 0, 0))
+///     .stroke(SvgColor::Hex(0x00_80_ff));
+/// assert_eq!(
+///     String::from(tree),
+///     "<circle fill=\"rgb(255,0,0)\" stroke=\"#0080ff\" />",
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum SvgColor {
+    /// Named color, such as `rebeccapurple`
+    Named(&'static str),
+    /// Red, green and blue components
+    Rgb(u8, u8, u8),
+    /// Red, green and blue components, with alpha (0 to 1)
+    Rgba(u8, u8, u8, f32),
+    /// Hue (degrees), saturation and lightness (percent)
+    Hsl(f32, f32, f32),
+    /// Hexadecimal `0xRRGGBB` value
+    Hex(u32),
+    /// No paint (`none`)
+    None,
+    /// Inherited `color` property (`currentColor`)
+    CurrentColor,
+    /// Paint server URL, such as `#gradient`
+    Url(String),
+}
+
+impl fmt::Display for SvgColor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SvgColor::Named(name) => write!(f, "{name}"),
+            SvgColor::Rgb(r, g, b) => write!(f, "rgb({r},{g},{b})"),
+            SvgColor::Rgba(r, g, b, a) => write!(f, "rgba({r},{g},{b},{a})"),
+            SvgColor::Hsl(h, s, l) => write!(f, "hsl({h},{s}%,{l}%)"),
+            SvgColor::Hex(rgb) => write!(f, "#{:06x}", rgb & 0xFF_FF_FF),
+            SvgColor::None => write!(f, "none"),
+            SvgColor::CurrentColor => write!(f, "currentColor"),
+            SvgColor::Url(url) => write!(f, "url({url})"),
+        }
+    }
+}
+
+impl From<SvgColor> for Value<'_> {
+    fn from(color: SvgColor) -> Self {
+        Value::from(color.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Tree;
+    use crate::svg::Svg;
+
+    #[test]
+    fn display() {
+        assert_eq!(SvgColor::Named("teal").to_string(), "teal");
+        assert_eq!(SvgColor::Rgba(0, 0, 0, 0.5).to_string(), "rgba(0,0,0,0.5)");
+        assert_eq!(
+            SvgColor::Hsl(120.0, 100.0, 25.5).to_string(),
+            "hsl(120,100%,25.5%)"
+        );
+        assert_eq!(SvgColor::Hex(0xABC).to_string(), "#000abc");
+        assert_eq!(SvgColor::None.to_string(), "none");
+        assert_eq!(SvgColor::CurrentColor.to_string(), "currentColor");
+        assert_eq!(SvgColor::Url("#grad".into()).to_string(), "url(#grad)");
+    }
+
+    #[test]
+    fn svg() {
+        let mut tree = Tree::new();
+        let mut svg = tree.root::<Svg>();
+        svg.circle().fill(SvgColor::Rgb(255, 0, 0)).close();
+        svg.rect()
+            .fill(SvgColor::Url("#g".into()))
+            .stroke(SvgColor::None)
+            .close();
+        let mut grad = svg.linear_gradient();
+        grad.stop().stop_color(SvgColor::CurrentColor).close();
+        grad.close();
+        svg.filter().fe_flood().flood_color(SvgColor::Named("navy"));
+        assert_eq!(
+            String::from(tree),
+            "<svg><circle fill=\"rgb(255,0,0)\" />\
+            <rect fill=\"url(#g)\" stroke=\"none\" />\
+            <linearGradient><stop stop-color=\"currentColor\" /></linearGradient>\
+            <filter><feFlood flood-color=\"navy\" /></filter></svg>"
+        );
+    }
+}
@@ -1,8 +1,11 @@
 // definition.rs
 // Copyright (C) 2025-2026  Douglas P Lau
 //
+use crate::error::Error;
+use crate::value::Value;
 use std::fmt;
 use std::fmt::Write;
+use std::str::FromStr;
 
 /// SVG [Path] definition builder
 ///
@@ -14,11 +17,11 @@ use std::fmt::Write;
 /// path.line((10.1, 20.2));
 /// path.cubic(None, (20, 25), (50, 55));
 /// path.close();
-/// println!("{path}");
+/// assert_eq!(path.to_string(), "m5 5l5.1 15.2s9.9 4.8 39.9 34.8z");
 /// ```
 ///
 /// [Path]: svg/struct.Path.html#method.d
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct PathDefBuilder {
     /// Absolute vs. relative output mode
     absolute: bool,
@@ -34,6 +37,25 @@ pub struct PathDefBuilder {
     y: f64,
     /// Definition string
     d: String,
+    /// Recorded segments (absolute coordinates)
+    segs: Vec<Seg>,
+}
+
+/// Path segment, in absolute coordinates
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Seg {
+    /// Move to a point
+    Move((f64, f64)),
+    /// Line to a point
+    Line((f64, f64)),
+    /// Cubic Bézier curve: control points and end point
+    Cubic((f64, f64), (f64, f64), (f64, f64)),
+    /// Quadratic Bézier curve: control point and end point
+    Quad((f64, f64), (f64, f64)),
+    /// Elliptical arc: radii, angle, large arc, sweep and end point
+    Arc(f64, f64, f64, bool, bool, (f64, f64)),
+    /// Close subpath
+    Close,
 }
 
 impl fmt::Display for PathDefBuilder {
@@ -43,6 +65,15 @@ impl fmt::Display for PathDefBuilder {
     }
 }
 
+impl PartialEq for PathDefBuilder {
+    /// Compare definition strings, ignoring precision and pen state
+    fn eq(&self, other: &Self) -> bool {
+        self.d == other.d
+    }
+}
+
+impl Eq for PathDefBuilder {}
+
 impl From<PathDefBuilder> for String {
     fn from(path: PathDefBuilder) -> Self {
         // zero-copy alternative to fmt::Display
@@ -50,6 +81,20 @@ impl From<PathDefBuilder> for String {
     }
 }
 
+impl From<PathDefBuilder> for Value<'_> {
+    fn from(path: PathDefBuilder) -> Self {
+        Value::from(path.d)
+    }
+}
+
+impl FromStr for PathDefBuilder {
+    type Err = Error;
+
+    fn from_str(d: &str) -> Result<Self, Self::Err> {
+        PathDefBuilder::parse(d)
+    }
+}
+
 impl PathDefBuilder {
     /// Create a new SVG path definition builder
     pub(crate) fn new() -> Self {
@@ -61,7 +106,92 @@ impl PathDefBuilder {
             x: 0.0,
             y: 0.0,
             d: String::new(),
+            segs: Vec::new(),
+        }
+    }
+
+    /// Parse SVG path data
+    ///
+    /// The output mode is set by the case of the first command, and the
+    /// precision is increased to fit the decimal places of all values.
+    ///
+    /// ```rust
+    /// use hatmil::{Error, PathDefBuilder};
+    ///
+    /// let mut path = PathDefBuilder::parse("M10 20L30 40z")?;
+    /// path.line([10, 0]);
+    /// assert_eq!(path.to_string(), "M10 20L30 40zV0");
+    /// assert_eq!(
+    ///     PathDefBuilder::parse("M0 0X1").err(),
+    ///     Some(Error::InvalidPathCommand('X')),
+    /// );
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn parse(d: &str) -> Result<Self, Error> {
+        let mut toks = PathTokens { d, pos: 0 };
+        let mut cmds = Vec::new();
+        let mut precision = 2;
+        let mut cmd = None;
+        while let Some(c) = toks.peek() {
+            if c.is_ascii_alphabetic() {
+                toks.pos += 1;
+                cmd = Some(c);
+            } else if matches!(cmd, None | Some('Z' | 'z')) {
+                return Err(Error::InvalidPathCommand(c));
+            }
+            let Some(c) = cmd else { break };
+            let count = match c.to_ascii_uppercase() {
+                'Z' => 0,
+                'H' | 'V' => 1,
+                'M' | 'L' | 'T' => 2,
+                'S' | 'Q' => 4,
+                'C' => 6,
+                'A' => 7,
+                _ => return Err(Error::InvalidPathCommand(c)),
+            };
+            let mut args = [0.0; 7];
+            for (i, arg) in args.iter_mut().enumerate().take(count) {
+                let flag = c.eq_ignore_ascii_case(&'A') && (i == 3 || i == 4);
+                let (v, digits) = toks.number(flag)?;
+                *arg = v;
+                precision = precision.max(digits);
+            }
+            cmds.push((c, args));
+            // subsequent pairs after a move are implicit lines
+            cmd = match c {
+                'M' => Some('L'),
+                'm' => Some('l'),
+                _ => cmd,
+            };
         }
+        let mut path = PathDefBuilder::new();
+        path.precision(precision);
+        path.absolute(
+            cmds.first().is_some_and(|(c, _)| c.is_ascii_uppercase()),
+        );
+        for (c, a) in cmds {
+            let (ox, oy) = if c.is_ascii_lowercase() {
+                (path.x, path.y)
+            } else {
+                (0.0, 0.0)
+            };
+            let p = |i: usize| (a[i] + ox, a[i + 1] + oy);
+            match c.to_ascii_uppercase() {
+                'M' => path.move_to(p(0)),
+                'L' => path.line(p(0)),
+                'H' => path.line((a[0] + ox, path.y)),
+                'V' => path.line((path.x, a[0] + oy)),
+                'C' => path.cubic(Some(p(0)), p(2), p(4)),
+                'S' => path.cubic(None, p(0), p(2)),
+                'Q' => path.quad(Some(p(0)), p(2)),
+                'T' => path.quad(None, p(0)),
+                'A' => {
+                    path.arc(a[0], a[1], a[2], a[3] != 0.0, a[4] != 0.0, p(5))
+                }
+                _ => path.close(),
+            };
+        }
+        Ok(path)
     }
 
     /// Set absolute or relative output mode
@@ -105,9 +235,32 @@ impl PathDefBuilder {
         self.value(y);
     }
 
+    /// Reflect a control point about the pen
+    fn reflect(&self, c: (f64, f64)) -> (f64, f64) {
+        (2.0 * self.x - c.0, 2.0 * self.y - c.1)
+    }
+
+    /// Check if two points are equal with specified precision
+    fn point_eq(&self, p1: (f64, f64), p2: (f64, f64)) -> bool {
+        self.value_eq(p1.0, p2.0) && self.value_eq(p1.1, p2.1)
+    }
+
+    /// Move the pen to a point, unless it is already there
+    ///
+    /// A new subpath is started if the path is empty; otherwise, a line is
+    /// drawn to the point.
+    fn pen_to(&mut self, x: f64, y: f64) {
+        if self.d.is_empty() {
+            self.move_to((x, y));
+        } else if !self.value_eq(x, self.x) || !self.value_eq(y, self.y) {
+            self.line((x, y));
+        }
+    }
+
     /// Close the current subpath
     pub fn close(&mut self) -> &mut Self {
         self.d.push('z');
+        self.segs.push(Seg::Close);
         (self.x, self.y) = (self.x0, self.y0);
         self
     }
@@ -130,6 +283,7 @@ impl PathDefBuilder {
         self.point(x, y);
         (self.x, self.y) = (p.0.into(), p.1.into());
         (self.x0, self.y0) = (self.x, self.y);
+        self.segs.push(Seg::Move((self.x, self.y)));
         self
     }
 
@@ -162,6 +316,7 @@ impl PathDefBuilder {
             }
         }
         (self.x, self.y) = (p.0.into(), p.1.into());
+        self.segs.push(Seg::Line((self.x, self.y)));
         self
     }
 
@@ -175,10 +330,12 @@ impl PathDefBuilder {
         let (mut x2, mut y2) = (p2.0.into(), p2.1.into());
         let p = p.into();
         let (mut x, mut y) = (p.0.into(), p.1.into());
+        let (c2, end) = ((x2, y2), (x, y));
         match p1 {
             Some(p1) => {
                 let p1 = p1.into();
                 let (mut x1, mut y1) = (p1.0.into(), p1.1.into());
+                self.segs.push(Seg::Cubic((x1, y1), c2, end));
                 if self.absolute {
                     self.d.push('C');
                 } else {
@@ -197,6 +354,11 @@ impl PathDefBuilder {
                 self.point(x, y);
             }
             None => {
+                let c1 = match self.segs.last() {
+                    Some(Seg::Cubic(_, c, _)) => self.reflect(*c),
+                    _ => (self.x, self.y),
+                };
+                self.segs.push(Seg::Cubic(c1, c2, end));
                 if self.absolute {
                     self.d.push('S');
                 } else {
@@ -223,10 +385,12 @@ impl PathDefBuilder {
     {
         let p = p.into();
         let (mut x, mut y) = (p.0.into(), p.1.into());
+        let end = (x, y);
         match p1 {
             Some(p1) => {
                 let p1 = p1.into();
                 let (mut x1, mut y1) = (p1.0.into(), p1.1.into());
+                self.segs.push(Seg::Quad((x1, y1), end));
                 if self.absolute {
                     self.d.push('Q');
                 } else {
@@ -241,6 +405,11 @@ impl PathDefBuilder {
                 self.point(x, y);
             }
             None => {
+                let c = match self.segs.last() {
+                    Some(Seg::Quad(c, _)) => self.reflect(*c),
+                    _ => (self.x, self.y),
+                };
+                self.segs.push(Seg::Quad(c, end));
                 if self.absolute {
                     self.d.push('T');
                 } else {
@@ -274,6 +443,8 @@ impl PathDefBuilder {
         let angle = angle.into();
         let p = p.into();
         let (mut x, mut y) = (p.0.into(), p.1.into());
+        self.segs
+            .push(Seg::Arc(rx, ry, angle, large_arc, sweep, (x, y)));
         if self.absolute {
             self.d.push('A');
         } else {
@@ -295,6 +466,266 @@ impl PathDefBuilder {
         (self.x, self.y) = (p.0.into(), p.1.into());
         self
     }
+
+    /// Draw an elliptical arc defined by its center point
+    ///
+    /// - `cx`, `cy`: Center point
+    /// - `rx`, `ry`: X and Y radii
+    /// - `start_deg`: Start angle, in degrees
+    /// - `sweep_deg`: Sweep angle, in degrees (positive is clockwise)
+    ///
+    /// If the pen is not at the start point, a line is drawn to it.
+    pub fn arc_center(
+        &mut self,
+        cx: f64,
+        cy: f64,
+        rx: f64,
+        ry: f64,
+        start_deg: f64,
+        sweep_deg: f64,
+    ) -> &mut Self {
+        let sweep_deg = sweep_deg.clamp(-360.0, 360.0);
+        let start = start_deg.to_radians();
+        self.pen_to(cx + rx * start.cos(), cy + ry * start.sin());
+        if sweep_deg.abs() < 360.0 {
+            self.arc_center_end(
+                cx,
+                cy,
+                rx,
+                ry,
+                start_deg + sweep_deg,
+                sweep_deg,
+            )
+        } else {
+            // full ellipse: end point would equal start point, so split it
+            let half = sweep_deg / 2.0;
+            self.arc_center_end(cx, cy, rx, ry, start_deg + half, half);
+            self.arc_center_end(cx, cy, rx, ry, start_deg + sweep_deg, half)
+        }
+    }
+
+    /// Draw an arc from the pen to an end angle
+    fn arc_center_end(
+        &mut self,
+        cx: f64,
+        cy: f64,
+        rx: f64,
+        ry: f64,
+        end_deg: f64,
+        sweep_deg: f64,
+    ) -> &mut Self {
+        let end = end_deg.to_radians();
+        let large_arc = sweep_deg.abs() > 180.0;
+        let sweep = sweep_deg > 0.0;
+        let p = (cx + rx * end.cos(), cy + ry * end.sin());
+        self.arc(rx, ry, 0.0, large_arc, sweep, p)
+    }
+
+    /// Draw a smooth curve through points using a Catmull-Rom spline
+    ///
+    /// Each segment is converted to a cubic Bézier curve.  A `tension` of
+    /// `0.5` gives a standard Catmull-Rom spline; `0.0` gives straight lines.
+    ///
+    /// If the pen is not at the first point, a line is drawn to it.
+    pub fn catmull_rom<P, V>(&mut self, points: &[P], tension: f64) -> &mut Self
+    where
+        P: Into<(V, V)> + Copy,
+        V: Into<f64> + Copy,
+    {
+        let pts: Vec<(f64, f64)> = points
+            .iter()
+            .map(|p| {
+                let p = (*p).into();
+                (p.0.into(), p.1.into())
+            })
+            .collect();
+        let Some(&first) = pts.first() else {
+            return self;
+        };
+        self.pen_to(first.0, first.1);
+        let n = pts.len();
+        // tangent at each point, reflecting across the end points
+        let tangent = |i: usize| {
+            let prev = if i > 0 {
+                pts[i - 1]
+            } else {
+                (2.0 * pts[0].0 - pts[1].0, 2.0 * pts[0].1 - pts[1].1)
+            };
+            let next = if i + 1 < n {
+                pts[i + 1]
+            } else {
+                (
+                    2.0 * pts[n - 1].0 - pts[n - 2].0,
+                    2.0 * pts[n - 1].1 - pts[n - 2].1,
+                )
+            };
+            (tension * (next.0 - prev.0), tension * (next.1 - prev.1))
+        };
+        for i in 1..n {
+            let (p0, p1) = (pts[i - 1], pts[i]);
+            let (m0, m1) = (tangent(i - 1), tangent(i));
+            let c1 = (p0.0 + m0.0 / 3.0, p0.1 + m0.1 / 3.0);
+            let c2 = (p1.0 - m1.0 / 3.0, p1.1 - m1.1 / 3.0);
+            self.cubic(Some(c1), c2, p1);
+        }
+        self
+    }
+
+    /// Reverse the direction of each subpath
+    ///
+    /// Closed subpaths keep the same starting point.  Smooth curves are
+    /// written with explicit control points.
+    pub fn reverse(&mut self) -> &mut Self {
+        let segs = std::mem::take(&mut self.segs);
+        self.d.clear();
+        (self.x0, self.y0, self.x, self.y) = (0.0, 0.0, 0.0, 0.0);
+        let mut start = (0.0, 0.0);
+        let mut sub = Vec::new();
+        for seg in segs {
+            match seg {
+                Seg::Move(p) => {
+                    self.reverse_subpath(start, &sub, false);
+                    sub.clear();
+                    start = p;
+                }
+                Seg::Close => {
+                    self.reverse_subpath(start, &sub, true);
+                    sub.clear();
+                }
+                _ => sub.push(seg),
+            }
+        }
+        self.reverse_subpath(start, &sub, false);
+        self
+    }
+
+    /// Write one subpath in reverse
+    fn reverse_subpath(
+        &mut self,
+        start: (f64, f64),
+        sub: &[Seg],
+        closed: bool,
+    ) {
+        let mut pts = Vec::with_capacity(sub.len() + 1);
+        pts.push(start);
+        for seg in sub {
+            match *seg {
+                Seg::Line(p)
+                | Seg::Cubic(_, _, p)
+                | Seg::Quad(_, p)
+                | Seg::Arc(_, _, _, _, _, p) => pts.push(p),
+                _ => (),
+            }
+        }
+        let last = pts[pts.len() - 1];
+        if closed {
+            self.move_to(start);
+            if !self.point_eq(last, start) {
+                self.line(last);
+            }
+        } else if !sub.is_empty() {
+            self.move_to(last);
+        }
+        for (i, seg) in sub.iter().enumerate().rev() {
+            let p = pts[i];
+            match *seg {
+                // closing line is implied by `z`
+                Seg::Line(_) if closed && i == 0 => (),
+                Seg::Line(_) => {
+                    self.line(p);
+                }
+                Seg::Cubic(c1, c2, _) => {
+                    self.cubic(Some(c2), c1, p);
+                }
+                Seg::Quad(c, _) => {
+                    self.quad(Some(c), p);
+                }
+                Seg::Arc(rx, ry, angle, large_arc, sweep, _) => {
+                    self.arc(rx, ry, angle, large_arc, !sweep, p);
+                }
+                _ => (),
+            }
+        }
+        if closed {
+            self.close();
+        }
+    }
+}
+
+/// SVG path data tokenizer
+struct PathTokens<'a> {
+    /// Path data
+    d: &'a str,
+    /// Current byte position
+    pos: usize,
+}
+
+impl PathTokens<'_> {
+    /// Skip separators and peek at the next character
+    fn peek(&mut self) -> Option<char> {
+        let rest = &self.d[self.pos..];
+        let trimmed = rest
+            .trim_start_matches(|c: char| c.is_ascii_whitespace() || c == ',');
+        self.pos += rest.len() - trimmed.len();
+        trimmed.chars().next()
+    }
+
+    /// Read a number (or arc flag), with its count of decimal places
+    fn number(&mut self, flag: bool) -> Result<(f64, usize), Error> {
+        self.peek();
+        let bytes = self.d.as_bytes();
+        let start = self.pos;
+        let mut end = start;
+        let mut digits = 0;
+        if flag {
+            if matches!(bytes.get(end), Some(b'0' | b'1')) {
+                end += 1;
+            }
+        } else {
+            if matches!(bytes.get(end), Some(b'+' | b'-')) {
+                end += 1;
+            }
+            while bytes.get(end).is_some_and(u8::is_ascii_digit) {
+                end += 1;
+            }
+            if bytes.get(end) == Some(&b'.') {
+                end += 1;
+                while bytes.get(end).is_some_and(u8::is_ascii_digit) {
+                    end += 1;
+                    digits += 1;
+                }
+            }
+            if matches!(bytes.get(end), Some(b'e' | b'E')) {
+                let mut exp = end + 1;
+                if matches!(bytes.get(exp), Some(b'+' | b'-')) {
+                    exp += 1;
+                }
+                if bytes.get(exp).is_some_and(u8::is_ascii_digit) {
+                    let e_start = end + 1;
+                    end = exp;
+                    while bytes.get(end).is_some_and(u8::is_ascii_digit) {
+                        end += 1;
+                    }
+                    // exponent shifts the decimal places
+                    let e: i64 = self.d[e_start..end].parse().unwrap_or(0);
+                    digits = usize::try_from(digits as i64 - e).unwrap_or(0);
+                }
+            }
+        }
+        match self.d[start..end].parse() {
+            Ok(v) => {
+                self.pos = end;
+                Ok((v, digits))
+            }
+            Err(_) => {
+                let token = self.d[start..]
+                    .split(|c: char| c.is_ascii_whitespace() || c == ',')
+                    .next()
+                    .unwrap_or_default();
+                Err(Error::InvalidPathNumber(token.into()))
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -371,6 +802,51 @@ mod test {
         assert_eq!(path.to_string(), "a20 25 90 1 0 50 10");
     }
 
+    /// Compare path definitions, allowing for rounding error
+    fn assert_path_near(d: &str, expected: &str) {
+        let split = |d: &str| -> Vec<String> {
+            d.replace(|c: char| c.is_ascii_alphabetic(), " $& ")
+                .split_whitespace()
+                .map(String::from)
+                .collect()
+        };
+        let (toks, exp) = (split(d), split(expected));
+        assert_eq!(toks.len(), exp.len(), "{d} != {expected}");
+        for (t, e) in toks.iter().zip(exp.iter()) {
+            match (t.parse::<f64>(), e.parse::<f64>()) {
+                (Ok(t), Ok(e)) => assert!((t - e).abs() < 0.01, "{d}"),
+                _ => assert_eq!(t, e, "{d} != {expected}"),
+            }
+        }
+    }
+
+    #[test]
+    fn arc_center() {
+        let mut path = PathDefBuilder::new();
+        path.absolute(true);
+        path.arc_center(50.0, 50.0, 20.0, 20.0, 0.0, 90.0);
+        assert_path_near(&path.to_string(), "M70 50A20 20 0 0 1 50 70");
+    }
+
+    #[test]
+    fn arc_center_large() {
+        let mut path = PathDefBuilder::new();
+        path.absolute(true);
+        path.move_to([0, 0]);
+        path.arc_center(50.0, 50.0, 20.0, 10.0, 90.0, -270.0);
+        assert_path_near(&path.to_string(), "M0 0L50 60A20 10 0 1 0 30 50");
+    }
+
+    #[test]
+    fn arc_center_full() {
+        let mut path = PathDefBuilder::new();
+        path.arc_center(0.0, 0.0, 10.0, 10.0, 180.0, 360.0);
+        assert_path_near(
+            &path.to_string(),
+            "m-10 0a10 10 0 0 1 20 0a10 10 0 0 1 -20 0",
+        );
+    }
+
     #[test]
     fn relative() {
         let mut path = PathDefBuilder::new();
@@ -398,6 +874,69 @@ mod test {
         assert_eq!(path.to_string(), "l2.222 9.994l2.222 -1.105h1");
     }
 
+    #[test]
+    fn parse_round_trip() {
+        for d in [
+            "M10 20L30 40z",
+            "M0 0H10V10C1 2 3 4 5 6S7 8 9 10Q1 1 2 2T3 3A5 5 0 1 0 10 10z",
+            "m1 2l3 4h1v-1c1 1 2 2 3 3s1 1 2 2q1 0 1 1t1 1a2 2 0 0 1 2 2z",
+            "m1 1zm2 2",
+            "M0.125 -3.5",
+            "",
+        ] {
+            assert_eq!(d.parse::<PathDefBuilder>().unwrap().to_string(), d);
+        }
+    }
+
+    #[test]
+    fn parse_compact() {
+        let path = PathDefBuilder::parse("M0 0 10 10 20 0").unwrap();
+        assert_eq!(path.to_string(), "M0 0L10 10L20 0");
+        let path = PathDefBuilder::parse("m0,0l.5.5-1-1e1").unwrap();
+        assert_eq!(path.to_string(), "m0 0l0.5 0.5l-1 -10");
+        let path = PathDefBuilder::parse("M1e-3 0 1.5E-2 2.5e1").unwrap();
+        assert_eq!(path.to_string(), "M0.001 0L0.015 25");
+        let path = PathDefBuilder::parse("m0 0a1 1 0 014 4").unwrap();
+        assert_eq!(path.to_string(), "m0 0a1 1 0 0 1 4 4");
+        let path = PathDefBuilder::parse("M5 5h5S15 15 20 20").unwrap();
+        let mut reference = PathDefBuilder::new();
+        reference.absolute(true).move_to([5, 5]).line([10, 5]);
+        reference.cubic(None, [15, 15], [20, 20]);
+        assert_eq!(path, reference);
+    }
+
+    #[test]
+    fn parse_errors() {
+        for (d, err) in [
+            ("10 20", Error::InvalidPathCommand('1')),
+            ("M0 0X1", Error::InvalidPathCommand('X')),
+            ("M0 0z 1", Error::InvalidPathCommand('1')),
+            ("M0 x", Error::InvalidPathNumber("x".into())),
+            ("M0", Error::InvalidPathNumber("".into())),
+            ("M0 0L--1 0", Error::InvalidPathNumber("--1".into())),
+            ("M0 0A1 1 0 2 0 1 1", Error::InvalidPathNumber("2".into())),
+        ] {
+            assert_eq!(PathDefBuilder::parse(d), Err(err));
+        }
+    }
+
+    #[test]
+    fn equal() {
+        let mut path = PathDefBuilder::new();
+        path.precision(3);
+        path.move_to([1, 2]).line([2.5, 4.0]).close();
+        let mut reference = PathDefBuilder::new();
+        reference.move_to([1, 2]).line([2.5, 4.0]).close();
+        assert_eq!(path, reference);
+        reference.line([1, 1]);
+        assert_ne!(path, reference);
+        let mut path = PathDefBuilder::new();
+        path.absolute(true).line([1.234, 0.0]);
+        let mut reference = PathDefBuilder::new();
+        reference.absolute(true).line([1.23, 0.0]);
+        assert_eq!(path, reference);
+    }
+
     #[test]
     fn close_move() {
         let mut path = PathDefBuilder::new();
@@ -412,6 +951,58 @@ mod test {
         assert_eq!(String::from(path), "m0 0l5 5v-5zm10 0l5 5v-5z");
     }
 
+    #[test]
+    fn reverse_triangle() {
+        let mut path = PathDefBuilder::new();
+        path.move_to([0, 0]);
+        path.line([10, 0]);
+        path.line([10, 10]);
+        path.close();
+        assert_eq!(path.to_string(), "m0 0h10v10z");
+        path.reverse();
+        assert_eq!(path.to_string(), "m0 0l10 10v-10z");
+        path.reverse();
+        assert_eq!(path.to_string(), "m0 0h10v10z");
+    }
+
+    #[test]
+    fn reverse_curves() {
+        let mut path = PathDefBuilder::new();
+        path.absolute(true);
+        path.move_to([0, 0]);
+        path.cubic(Some((0, 5)), (5, 10), (10, 10));
+        path.cubic(None, (20, 5), (20, 0));
+        path.arc(5, 5, 0, false, true, (10, 0));
+        path.close();
+        path.reverse();
+        assert_eq!(
+            path.to_string(),
+            "M0 0H10A5 5 0 0 0 20 0C20 5 15 10 10 10C5 10 0 5 0 0z"
+        );
+    }
+
+    #[test]
+    fn reverse_open() {
+        let mut path = PathDefBuilder::new();
+        path.move_to([1, 1]);
+        path.line([4, 1]);
+        path.quad(Some((6, 3)), (4, 5));
+        path.reverse();
+        assert_eq!(path.to_string(), "m4 5q2 -2 0 -4h-3");
+    }
+
+    #[test]
+    fn catmull_rom() {
+        let mut path = PathDefBuilder::new();
+        path.absolute(true);
+        path.catmull_rom(&[(0, 0), (10, 10), (20, 0), (30, 10), (40, 0)], 0.5);
+        assert_eq!(
+            path.to_string(),
+            "M0 0C3.33 3.33 6.67 10 10 10C13.33 10 16.67 0 20 0\
+            C23.33 0 26.67 10 30 10C33.33 10 36.67 3.33 40 0"
+        );
+    }
+
     #[test]
     fn move_move() {
         let mut path = PathDefBuilder::new();
@@ -0,0 +1,117 @@
+// error.rs
+//
+// Copyright (C) 2026  Douglas P Lau
+//
+use std::fmt;
+
+/// Error building a tree or parsing values
+///
+/// ```rust
+/// use hatmil::{Error, Tree};
+///
+/// let mut tree = Tree::new();
+/// assert_eq!(tree.try_close().err(), Some(Error::UnbalancedClose));
+/// let err = tree.try_custom("widget").err().unwrap();
+/// assert_eq!(err.to_string(), "invalid custom element name: widget");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// Close with no open elements
+    UnbalancedClose,
+    /// Attribute added after element content
+    AttributeAfterContent {
+        /// Element tag
+        tag: String,
+        /// Attribute name
+        attr: String,
+    },
+    /// Invalid attribute name
+    InvalidAttributeName(String),
+    /// Invalid custom element name
+    InvalidElementName(String),
+    /// URL with a disallowed scheme, such as `javascript:`
+    DisallowedUrlScheme(String),
+    /// Close with a different innermost open element
+    MismatchedClose {
+        /// Expected tag
+        expected: String,
+        /// Actual innermost open tag
+        actual: String,
+    },
+    /// Invalid command in SVG path data
+    InvalidPathCommand(char),
+    /// Invalid number in SVG path data
+    InvalidPathNumber(String),
+    /// Element added beyond the maximum depth
+    DepthExceeded(usize),
+    /// Raw content with broken markup
+    InvalidRaw(String),
+    /// Duplicate attribute on an element (`validate` feature)
+    DuplicateAttribute {
+        /// Element tag
+        tag: String,
+        /// Attribute name
+        attr: String,
+    },
+    /// Content added to an element while a child is open (`validate` feature)
+    WrongParent {
+        /// Parent element tag
+        parent: String,
+        /// Open child element tag
+        open: String,
+        /// Added element tag (or content type)
+        child: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::UnbalancedClose => write!(f, "close with no open elements"),
+            Error::AttributeAfterContent { tag, attr } => {
+                write!(
+                    f,
+                    "cannot add {attr} attribute to <{tag}> after content"
+                )
+            }
+            Error::InvalidAttributeName(attr) => {
+                write!(f, "invalid attribute name: {attr:?}")
+            }
+            Error::InvalidElementName(tag) => {
+                write!(f, "invalid custom element name: {tag}")
+            }
+            Error::DisallowedUrlScheme(scheme) => {
+                write!(f, "disallowed URL scheme: {scheme}")
+            }
+            Error::MismatchedClose { expected, actual } => {
+                write!(f, "expected to close <{expected}>, found <{actual}>")
+            }
+            Error::InvalidPathCommand(c) => {
+                write!(f, "invalid path command: {c:?}")
+            }
+            Error::InvalidPathNumber(num) => {
+                write!(f, "invalid path number: {num:?}")
+            }
+            Error::DepthExceeded(depth) => {
+                write!(f, "maximum depth of {depth} exceeded")
+            }
+            Error::InvalidRaw(problem) => write!(f, "raw content {problem}"),
+            Error::DuplicateAttribute { tag, attr } => {
+                write!(f, "duplicate {attr} attribute on <{tag}>")
+            }
+            Error::WrongParent {
+                parent,
+                open,
+                child,
+            } => {
+                write!(
+                    f,
+                    "cannot add {child} to <{parent}> while <{open}> is open"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
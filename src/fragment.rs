@@ -0,0 +1,186 @@
+// fragment.rs
+//
+// Copyright (C) 2026  Douglas P Lau
+//
+use crate::html::Custom;
+use crate::tree::{Element, Tree};
+use crate::value::Value;
+use std::borrow::Cow;
+use std::fmt;
+
+/// Standalone HTML fragment
+///
+/// Unlike a [Tree], a fragment has no `DOCTYPE` or `<html>` element.  It can
+/// be appended to a tree or element using `append_fragment`, or used as an
+/// attribute value (e.g. `srcdoc`).
+///
+/// ```rust
+/// use hatmil::{Fragment, Tree, html::{Div, Li, Ul}};
+///
+/// fn item(text: &str) -> Fragment {
+///     let mut frag = Fragment::new();
+///     frag.root::<Li>().class("item").cdata(text);
+///     frag
+/// }
+///
+/// let frag = item("Nori");
+/// let mut tree = Tree::new();
+/// tree.root::<Ul>().append_fragment(&frag);
+/// assert_eq!(
+///     String::from(tree),
+///     "<ul><li class=\"item\">Nori</li></ul>",
+/// );
+/// ```
+#[derive(Default)]
+pub struct Fragment {
+    /// Fragment tree
+    tree: Tree,
+}
+
+impl fmt::Display for Fragment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.tree)
+    }
+}
+
+impl From<Fragment> for String {
+    fn from(frag: Fragment) -> Self {
+        String::from(frag.tree)
+    }
+}
+
+impl Fragment {
+    /// Create an empty fragment
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create root `E` element
+    ///
+    /// - `E`: Element type, from either the [html] or [svg] modules
+    ///
+    /// [html]: crate::html
+    /// [svg]: crate::svg
+    #[must_use]
+    pub fn root<'t, E>(&'t mut self) -> E
+    where
+        E: Element<'t>,
+    {
+        self.tree.root()
+    }
+
+    /// Create root custom element
+    ///
+    /// - `tag`: Element tag, which must contain a hyphen and only lower-case
+    ///   ASCII letters, digits and hyphens
+    #[must_use]
+    pub fn custom(&mut self, tag: impl Into<Cow<'static, str>>) -> Custom<'_> {
+        self.tree.custom(tag)
+    }
+
+    /// Add a comment
+    pub fn comment<'a, V>(&mut self, com: V) -> &mut Self
+    where
+        V: Into<Value<'a>>,
+    {
+        self.tree.comment(com);
+        self
+    }
+
+    /// Add raw content
+    ///
+    /// **WARNING**: `trusted` is used verbatim, with no escaping; do not call
+    /// with untrusted content.
+    pub fn raw(&mut self, trusted: impl AsRef<str>) -> &mut Self {
+        self.tree.raw(trusted);
+        self
+    }
+
+    /// Append another fragment
+    pub fn append_fragment(&mut self, frag: &Fragment) -> &mut Self {
+        self.tree.append_fragment(frag);
+        self
+    }
+
+    /// Get the fragment text, with any open elements closed
+    pub(crate) fn closed_str(&self) -> Cow<'_, str> {
+        self.tree.closed_str()
+    }
+
+    /// Convert into a string, closing any open elements
+    pub fn into_string(self) -> String {
+        String::from(self)
+    }
+
+    /// Convert into a value, closing any open elements
+    ///
+    /// This can be used to embed a fragment into an attribute value, such as
+    /// `srcdoc`.
+    pub fn into_value(self) -> Value<'static> {
+        Value::from(self.into_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::html::*;
+
+    /// Build a card fragment
+    fn card(title: &str) -> Fragment {
+        let mut frag = Fragment::new();
+        let mut div = frag.root::<Div>();
+        div.class("card");
+        div.h2().cdata(title);
+        frag
+    }
+
+    #[test]
+    fn append() {
+        let frag = card("A & B");
+        let mut tree = Tree::new();
+        let mut html = tree.html();
+        let mut body = html.body();
+        body.append_fragment(&frag);
+        body.p().cdata("after");
+        assert_eq!(
+            String::from(tree),
+            "<!DOCTYPE html><html><body><div class=\"card\"><h2>A &amp; B</h2>\
+            </div><p>after</p></body></html>"
+        );
+        let mut tree = Tree::new();
+        let mut ul = tree.root::<Ul>();
+        ul.li().append_fragment(&frag).close();
+        ul.li().append_fragment(&frag);
+        assert_eq!(
+            String::from(tree),
+            "<ul><li><div class=\"card\"><h2>A &amp; B</h2></div></li>\
+            <li><div class=\"card\"><h2>A &amp; B</h2></div></li></ul>"
+        );
+    }
+
+    #[test]
+    fn srcdoc() {
+        let mut frag = Fragment::new();
+        frag.root::<P>().title("\"Q\"").cdata("Hello");
+        let mut tree = Tree::new();
+        tree.root::<IFrame>().srcdoc(frag.into_value());
+        assert_eq!(
+            String::from(tree),
+            "<iframe srcdoc=\"<p title=&quot;&amp;quot;Q&amp;quot;&quot;>\
+            Hello</p>\"></iframe>"
+        );
+    }
+
+    #[test]
+    fn into_string() {
+        let mut frag = Fragment::new();
+        frag.comment("start");
+        frag.root::<Em>().cdata("A").close();
+        frag.append_fragment(&card("Title"));
+        assert_eq!(
+            frag.into_string(),
+            "<!--start--><em>A</em><div class=\"card\"><h2>Title</h2></div>"
+        );
+    }
+}
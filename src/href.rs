@@ -0,0 +1,146 @@
+// href.rs
+//
+// Copyright (C) 2026  Douglas P Lau
+//
+use crate::error::Error;
+use crate::value::Value;
+use std::fmt;
+
+/// URL schemes allowed by [SafeHref::new]
+const SCHEMES: &[&str] = &["http", "https", "mailto", "tel"];
+
+/// Link URL with a checked scheme
+///
+/// Relative URLs are allowed, as are `http`, `https`, `mailto` and `tel`
+/// schemes.
+///
+/// ```rust
+/// use hatmil::{Error, SafeHref, Tree, html::A};
+///
+/// let mut tree = Tree::new();
+/// tree.root::<A>().href(SafeHref::new("https://example.com/")?);
+/// assert_eq!(
+///     String::from(tree),
+///     "<a href=\"https://example.com/\"></a>",
+/// );
+/// assert_eq!(
+///     SafeHref::new("javascript:alert(1)").err(),
+///     Some(Error::DisallowedUrlScheme("javascript".into())),
+/// );
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SafeHref(String);
+
+impl fmt::Display for SafeHref {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<SafeHref> for Value<'_> {
+    fn from(href: SafeHref) -> Self {
+        Value::from(href.0)
+    }
+}
+
+impl SafeHref {
+    /// Create a link URL, checking its scheme
+    ///
+    /// Returns [Error::DisallowedUrlScheme] for any other scheme, such as
+    /// `javascript:` or a mistyped `htps:`.
+    pub fn new(url: &str) -> Result<Self, Error> {
+        match scheme(url) {
+            Some(s) if !SCHEMES.iter().any(|a| a.eq_ignore_ascii_case(&s)) => {
+                Err(Error::DisallowedUrlScheme(s))
+            }
+            _ => Ok(SafeHref(url.to_string())),
+        }
+    }
+
+    /// Create a link URL without checking its scheme
+    ///
+    /// This can be used for `data:` URLs.
+    pub fn unchecked(url: &str) -> Self {
+        SafeHref(url.to_string())
+    }
+
+    /// Get the URL as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Get the scheme of a URL, if it has one
+///
+/// Leading spaces and control characters, and any tabs or newlines, are
+/// ignored (as browsers do).
+fn scheme(url: &str) -> Option<String> {
+    let url = url.trim_start_matches(|c: char| c <= ' ');
+    let mut scheme = String::new();
+    for c in url.chars().filter(|c| !matches!(c, '\t' | '\n' | '\r')) {
+        match c {
+            ':' => return (!scheme.is_empty()).then_some(scheme),
+            'a'..='z' | 'A'..='Z' => scheme.push(c),
+            '0'..='9' | '+' | '-' | '.' if !scheme.is_empty() => scheme.push(c),
+            _ => return None,
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Tree;
+    use crate::html::A;
+
+    #[test]
+    fn allowed() {
+        for url in [
+            "http://example.com",
+            "HTTPS://example.com/a?b=c",
+            "mailto:me@example.com",
+            "tel:+1-555-0100",
+            "#top",
+            "/index.html",
+            "./a.html",
+            "../b.html",
+            "page.html?x=y:z",
+            "",
+        ] {
+            assert_eq!(SafeHref::new(url).unwrap().as_str(), url);
+        }
+    }
+
+    #[test]
+    fn disallowed() {
+        for (url, scheme) in [
+            ("javascript:alert(1)", "javascript"),
+            (" \x01JavaScript:void(0)", "JavaScript"),
+            ("java\tscript:x", "javascript"),
+            ("htps://example.com", "htps"),
+            ("data:text/html,hi", "data"),
+        ] {
+            assert_eq!(
+                SafeHref::new(url),
+                Err(Error::DisallowedUrlScheme(scheme.into()))
+            );
+        }
+        assert_eq!(
+            SafeHref::unchecked("data:text/plain,hi").as_str(),
+            "data:text/plain,hi"
+        );
+    }
+
+    #[test]
+    fn href() {
+        let mut tree = Tree::new();
+        tree.root::<A>()
+            .href(SafeHref::new("https://example.com/?a=1&b=2").unwrap());
+        assert_eq!(
+            String::from(tree),
+            "<a href=\"https://example.com/?a=1&amp;b=2\"></a>"
+        );
+    }
+}
@@ -3,20 +3,270 @@
 // Copyright (C) 2025-2026  Douglas P Lau
 //
 //! HTML Elements -- _HyperText Markup Language_
+//!
+//! Each element has methods for attributes and permitted child elements:
+//!
+//! ```rust
+//! use hatmil::{Tree, html::Form};
+//!
+//! let mut tree = Tree::new();
+//! let mut form = tree.root::<Form>();
+//! form.action("/login").method("post");
+//! let mut label = form.label();
+//! label.r#for("user").cdata_len("User name (required)", 9);
+//! label.close();
+//! form.input().id("user").name("user").required();
+//! form.button().r#type("submit").cdata("Log in");
+//! assert_eq!(
+//!     String::from(tree),
+//!     "<form action=\"/login\" method=\"post\"><label for=\"user\">User name\
+//!     </label><input id=\"user\" name=\"user\" required>\
+//!     <button type=\"submit\">Log in</button></form>",
+//! );
+//! ```
+use crate::fragment::Fragment;
 use crate::svg::Svg;
 use crate::tree::{ElemType, Element, Tree};
 use crate::value::Value;
+use std::borrow::Cow;
+use std::fmt;
+
+/// `rel` attribute [link type]
+///
+/// Link types can be combined into a [RelList] with `+` or `and`.
+///
+/// ```rust
+/// use hatmil::{Tree, html::{Link, Rel}};
+///
+/// let mut tree = Tree::new();
+/// tree.root::<Link>().rel(Rel::Stylesheet + Rel::Preload);
+/// assert_eq!(String::from(tree), "<link rel=\"stylesheet preload\" />");
+/// ```
+///
+/// [link type]: https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Attributes/rel
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Rel {
+    /// `alternate`
+    Alternate,
+    /// `author`
+    Author,
+    /// `bookmark`
+    Bookmark,
+    /// `canonical`
+    Canonical,
+    /// `dns-prefetch`
+    DnsPrefetch,
+    /// `external`
+    External,
+    /// `help`
+    Help,
+    /// `icon`
+    Icon,
+    /// `license`
+    License,
+    /// `manifest`
+    Manifest,
+    /// `me`
+    Me,
+    /// `modulepreload`
+    ModulePreload,
+    /// `next`
+    Next,
+    /// `nofollow`
+    NoFollow,
+    /// `noopener`
+    NoOpener,
+    /// `noreferrer`
+    NoReferrer,
+    /// `opener`
+    Opener,
+    /// `pingback`
+    Pingback,
+    /// `preconnect`
+    Preconnect,
+    /// `prefetch`
+    Prefetch,
+    /// `preload`
+    Preload,
+    /// `prev`
+    Prev,
+    /// `privacy-policy`
+    PrivacyPolicy,
+    /// `search`
+    Search,
+    /// `stylesheet`
+    Stylesheet,
+    /// `tag`
+    Tag,
+    /// `terms-of-service`
+    TermsOfService,
+}
+
+impl fmt::Display for Rel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<Rel> for Value<'_> {
+    fn from(rel: Rel) -> Self {
+        Value::from(rel.as_str())
+    }
+}
+
+/// List of `rel` attribute [Rel] link types
+///
+/// Duplicate link types are skipped.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RelList {
+    /// Link types, in order
+    rels: Vec<Rel>,
+}
+
+impl fmt::Display for RelList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, rel) in self.rels.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{rel}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Rel> for RelList {
+    fn from(rel: Rel) -> Self {
+        RelList { rels: vec![rel] }
+    }
+}
+
+impl From<RelList> for Value<'_> {
+    fn from(list: RelList) -> Self {
+        Value::from(list.to_string())
+    }
+}
+
+impl FromIterator<Rel> for RelList {
+    fn from_iter<I: IntoIterator<Item = Rel>>(iter: I) -> Self {
+        let mut list = RelList::new();
+        for rel in iter {
+            list.push(rel);
+        }
+        list
+    }
+}
+
+impl std::ops::Add for Rel {
+    type Output = RelList;
+
+    fn add(self, rhs: Rel) -> RelList {
+        self.and(rhs)
+    }
+}
+
+impl std::ops::Add<Rel> for RelList {
+    type Output = RelList;
+
+    fn add(self, rhs: Rel) -> RelList {
+        self.and(rhs)
+    }
+}
+
+impl RelList {
+    /// Create an empty list
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Chain another link type
+    pub fn and(mut self, rel: Rel) -> Self {
+        self.push(rel);
+        self
+    }
+
+    /// Add a link type to the end of the list
+    pub fn push(&mut self, rel: Rel) -> &mut Self {
+        if !self.rels.contains(&rel) {
+            self.rels.push(rel);
+        }
+        self
+    }
+
+    /// Check if the list is empty
+    pub fn is_empty(&self) -> bool {
+        self.rels.is_empty()
+    }
+}
+
+impl Rel {
+    /// Chain another link type, making a list
+    pub fn and(self, rel: Rel) -> RelList {
+        RelList::from(self).and(rel)
+    }
+
+    /// Get the attribute value
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Rel::Alternate => "alternate",
+            Rel::Author => "author",
+            Rel::Bookmark => "bookmark",
+            Rel::Canonical => "canonical",
+            Rel::DnsPrefetch => "dns-prefetch",
+            Rel::External => "external",
+            Rel::Help => "help",
+            Rel::Icon => "icon",
+            Rel::License => "license",
+            Rel::Manifest => "manifest",
+            Rel::Me => "me",
+            Rel::ModulePreload => "modulepreload",
+            Rel::Next => "next",
+            Rel::NoFollow => "nofollow",
+            Rel::NoOpener => "noopener",
+            Rel::NoReferrer => "noreferrer",
+            Rel::Opener => "opener",
+            Rel::Pingback => "pingback",
+            Rel::Preconnect => "preconnect",
+            Rel::Prefetch => "prefetch",
+            Rel::Preload => "preload",
+            Rel::Prev => "prev",
+            Rel::PrivacyPolicy => "privacy-policy",
+            Rel::Search => "search",
+            Rel::Stylesheet => "stylesheet",
+            Rel::Tag => "tag",
+            Rel::TermsOfService => "terms-of-service",
+        }
+    }
+}
+
+/// Typed `rel` attribute method
+macro_rules! rel_tokens {
+    () => {
+        /// Add `rel` attribute from link types
+        ///
+        /// Link types are space-separated, in order, skipping duplicates.
+        pub fn rel_tokens<I>(&mut self, rels: I) -> &mut Self
+        where
+            I: IntoIterator<Item = Rel>,
+        {
+            self.rel(rels.into_iter().collect::<RelList>())
+        }
+    };
+}
 
 // A element
 macro_rules! a_items {
     ( $el:literal ) => {
         html_attr!($el, download);
         html_attr!($el, href);
+        url_attr!(href_url, "href");
         html_attr!($el, hreflang);
         /* interestfor */
         html_attr!($el, ping);
         html_attr!($el, referrerpolicy);
         html_attr!($el, rel);
+        rel_tokens!();
         html_attr!($el, target);
         html_attr!($el, r#type, "type");
         // NOTE: only transparent content (interactive, or "a" disallowed)
@@ -56,10 +306,12 @@ macro_rules! area_items {
         html_attr!($el, coords);
         html_attr!($el, download);
         html_attr!($el, href);
+        url_attr!(href_url, "href");
         /* interestfor */
         html_attr!($el, ping);
         html_attr!($el, referrerpolicy);
         html_attr!($el, rel);
+        rel_tokens!();
         html_attr!($el, shape);
         html_attr!($el, target);
         // no content (void)
@@ -92,6 +344,7 @@ macro_rules! audio_items {
         html_attr!($el, r#loop, "loop", true);
         html_attr!($el, preload);
         html_attr!($el, src);
+        url_attr!(src_url, "src");
         // NOTE: special content rules
         elem_method!(source, Source);
         elem_method!(track, Track);
@@ -143,6 +396,7 @@ html_elem!(
 macro_rules! base_items {
     ( $el:literal ) => {
         html_attr!($el, href);
+        url_attr!(href_url, "href");
         html_attr!($el, target);
         // no content (void)
     };
@@ -152,6 +406,24 @@ html_elem!("base", Base, "Base URL", base_items(), ElemType::HtmlVoid);
 // Body element
 macro_rules! body_items {
     ( $el:literal ) => {
+        event_attr!(onafterprint);
+        event_attr!(onbeforeprint);
+        event_attr!(onbeforeunload);
+        event_attr!(onhashchange);
+        event_attr!(onlanguagechange);
+        event_attr!(onmessage);
+        event_attr!(onmessageerror);
+        event_attr!(onoffline);
+        event_attr!(ononline);
+        event_attr!(onpagehide);
+        event_attr!(onpagereveal);
+        event_attr!(onpageshow);
+        event_attr!(onpageswap);
+        event_attr!(onpopstate);
+        event_attr!(onrejectionhandled);
+        event_attr!(onstorage);
+        event_attr!(onunhandledrejection);
+        event_attr!(onunload);
         flow_content!(abbr, cite, form);
     };
 }
@@ -165,6 +437,52 @@ macro_rules! br_items {
 }
 html_elem!("br", Br, "Line Break", br_items(), ElemType::HtmlVoid);
 
+/// [Button] `type` attribute value
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ButtonType {
+    /// `button`: no default behavior
+    Button,
+    /// `reset`: reset form controls
+    Reset,
+    /// `submit`: submit form data (default)
+    Submit,
+}
+
+impl fmt::Display for ButtonType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<ButtonType> for Value<'_> {
+    fn from(tp: ButtonType) -> Self {
+        Value::from(tp.as_str())
+    }
+}
+
+impl ButtonType {
+    /// Get the attribute value
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            ButtonType::Button => "button",
+            ButtonType::Reset => "reset",
+            ButtonType::Submit => "submit",
+        }
+    }
+}
+
+impl Button<'_> {
+    /// Add `type` attribute
+    pub fn type_btn(&mut self, tp: ButtonType) -> &mut Self {
+        self.r#type(tp)
+    }
+
+    /// Add `type` attribute (same as [type_btn](Button::type_btn))
+    pub fn button_type(&mut self, tp: ButtonType) -> &mut Self {
+        self.type_btn(tp)
+    }
+}
+
 // Button element
 macro_rules! button_items {
     ( $el:literal ) => {
@@ -239,6 +557,63 @@ macro_rules! colgroup_items {
 }
 html_elem!("colgroup", ColGroup, "Table Column Group", colgroup_items());
 
+/// Custom element (web component), such as `<my-widget>`
+///
+/// Create with [Tree::custom], or the `custom` method of a parent element.
+pub struct Custom<'t> {
+    /// Borrowed Tree
+    pub(crate) tree: &'t mut Tree,
+    /// Node depth
+    pub(crate) depth: usize,
+}
+
+// Custom element
+macro_rules! custom_items {
+    () => {
+        // NOTE: content model is defined by the component
+        flow_content!(abbr, cite, form);
+    };
+}
+
+/// Custom element items
+impl<'t> Custom<'t> {
+    custom_items!();
+
+    /// Close the element
+    ///
+    /// - Closes all child elements
+    /// - Adds the closing tag
+    pub fn close(&'t mut self) -> &'t mut Tree {
+        self.tree.close_to(self.depth);
+        self.tree
+    }
+
+    /// Reserve capacity for additional document text, in bytes
+    pub fn reserve(&mut self, additional: usize) -> &mut Self {
+        self.tree.reserve(additional);
+        self
+    }
+
+    cond_methods!();
+}
+
+global_attributes!(Custom);
+
+#[cfg(feature = "codegen")]
+impl Custom<'_> {
+    /// Get element methods, for code generation
+    pub(crate) fn codegen() -> crate::codegen::ElemInfo {
+        let mut info = crate::codegen::ElemInfo::new(false, "", "Custom");
+        codegen_table!(
+            info,
+            custom_items!();
+            global_attributes!();
+            aria_attributes!();
+        );
+        info
+    }
+}
+
 // Data element
 macro_rules! data_items {
     ( $el:literal ) => {
@@ -352,6 +727,7 @@ macro_rules! embed_items {
     ( $el:literal ) => {
         html_attr!($el, height);
         html_attr!($el, src);
+        url_attr!(src_url, "src");
         html_attr!($el, r#type, "type");
         html_attr!($el, width);
         // no content (void)
@@ -365,6 +741,22 @@ html_elem!(
     ElemType::HtmlVoid
 );
 
+// FencedFrame element
+macro_rules! fencedframe_items {
+    ( $el:literal ) => {
+        html_attr!($el, allow);
+        html_attr!($el, height);
+        html_attr!($el, width);
+        // no content allowed (nested browsing context)
+    };
+}
+html_elem!(
+    "fencedframe",
+    FencedFrame,
+    "Fenced Frame",
+    fencedframe_items()
+);
+
 // FeildSet element
 macro_rules! fieldset_items {
     ( $el:literal ) => {
@@ -416,6 +808,7 @@ macro_rules! form_items {
         html_attr!($el, autocomplete);
         html_attr!($el, name);
         html_attr!($el, rel);
+        rel_tokens!();
         html_attr!($el, action);
         html_attr!($el, enctype);
         html_attr!($el, method);
@@ -449,6 +842,69 @@ macro_rules! head_items {
 }
 html_elem!("head", Head, "Header / Document Metadata", head_items());
 
+impl Head<'_> {
+    /// Default `viewport` metadata content
+    pub const VIEWPORT: &'static str = "width=device-width, initial-scale=1";
+
+    /// Add `<meta charset="utf-8">` child element
+    pub fn charset_utf8(&mut self) -> &mut Self {
+        self.meta().charset_utf8().close();
+        self
+    }
+
+    /// Add `<meta name="viewport">` child element
+    ///
+    /// - `content`: Viewport content, such as [Head::VIEWPORT]
+    pub fn viewport(&mut self, content: &str) -> &mut Self {
+        self.meta()
+            .name(MetaName::Viewport)
+            .content(content)
+            .close();
+        self
+    }
+
+    /// Add `<meta name="description">` child element
+    pub fn description(&mut self, text: &str) -> &mut Self {
+        self.meta()
+            .name(MetaName::Description)
+            .content(text)
+            .close();
+        self
+    }
+
+    /// Add `<link rel="canonical">` child element
+    pub fn canonical(&mut self, href: &str) -> &mut Self {
+        self.link().rel("canonical").href(href).close();
+        self
+    }
+
+    /// Add `<link rel="stylesheet">` child element
+    pub fn stylesheet(&mut self, href: &str) -> Link<'_> {
+        let mut link = self.link();
+        link.rel("stylesheet").href(href);
+        link
+    }
+
+    /// Add `<link rel="icon">` child element
+    ///
+    /// A MIME type, such as `image/png`, can be added with `r#type`.
+    pub fn icon(&mut self, href: &str) -> Link<'_> {
+        let mut link = self.link();
+        link.rel("icon").href(href);
+        link
+    }
+
+    /// Add `<link rel="preload">` child element
+    ///
+    /// - `href`: Resource URL
+    /// - `as_`: Resource type, such as `font` or `script`
+    pub fn preload(&mut self, href: &str, as_: &str) -> Link<'_> {
+        let mut link = self.link();
+        link.rel("preload").href(href).r#as(as_);
+        link
+    }
+}
+
 // Header element
 macro_rules! header_items {
     ( $el:literal ) => {
@@ -484,6 +940,8 @@ html_elem!("hr", Hr, "Horizontal Rule", hr_items(), ElemType::HtmlVoid);
 // Html element
 macro_rules! html_items {
     ( $el:literal ) => {
+        // NOTE: needed for XHTML
+        html_attr!($el, xmlns);
         elem_method!(head, Head);
         elem_method!(body, Body);
         comment_raw_methods!();
@@ -511,6 +969,7 @@ macro_rules! iframe_items {
         html_attr!($el, referrerpolicy);
         html_attr!($el, sandbox);
         html_attr!($el, src);
+        url_attr!(src_url, "src");
         html_attr!($el, srcdoc);
         html_attr!($el, width);
         // no content allowed (nested browsing context)
@@ -532,6 +991,7 @@ macro_rules! img_items {
         html_attr!($el, referrerpolicy);
         html_attr!($el, sizes);
         html_attr!($el, src);
+        url_attr!(src_url, "src");
         html_attr!($el, srcset);
         html_attr!($el, width);
         html_attr!($el, usemap);
@@ -546,6 +1006,109 @@ html_elem!(
     ElemType::HtmlVoid
 );
 
+/// [Input] `type` attribute value
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputType {
+    /// `button`
+    Button,
+    /// `checkbox`
+    Checkbox,
+    /// `color`
+    Color,
+    /// `date`
+    Date,
+    /// `datetime-local`
+    DatetimeLocal,
+    /// `email`
+    Email,
+    /// `file`
+    File,
+    /// `hidden`
+    Hidden,
+    /// `image`
+    Image,
+    /// `month`
+    Month,
+    /// `number`
+    Number,
+    /// `password`
+    Password,
+    /// `radio`
+    Radio,
+    /// `range`
+    Range,
+    /// `reset`
+    Reset,
+    /// `search`
+    Search,
+    /// `submit`
+    Submit,
+    /// `tel`
+    Tel,
+    /// `text`
+    Text,
+    /// `time`
+    Time,
+    /// `url`
+    Url,
+    /// `week`
+    Week,
+}
+
+impl fmt::Display for InputType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<InputType> for Value<'_> {
+    fn from(tp: InputType) -> Self {
+        Value::from(tp.as_str())
+    }
+}
+
+impl InputType {
+    /// Get the attribute value
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            InputType::Button => "button",
+            InputType::Checkbox => "checkbox",
+            InputType::Color => "color",
+            InputType::Date => "date",
+            InputType::DatetimeLocal => "datetime-local",
+            InputType::Email => "email",
+            InputType::File => "file",
+            InputType::Hidden => "hidden",
+            InputType::Image => "image",
+            InputType::Month => "month",
+            InputType::Number => "number",
+            InputType::Password => "password",
+            InputType::Radio => "radio",
+            InputType::Range => "range",
+            InputType::Reset => "reset",
+            InputType::Search => "search",
+            InputType::Submit => "submit",
+            InputType::Tel => "tel",
+            InputType::Text => "text",
+            InputType::Time => "time",
+            InputType::Url => "url",
+            InputType::Week => "week",
+        }
+    }
+}
+
+impl Input<'_> {
+    /// Add `type` attribute
+    pub fn type_input(&mut self, tp: InputType) -> &mut Self {
+        self.r#type(tp)
+    }
+
+    /// Add `type` attribute (same as [type_input](Input::type_input))
+    pub fn input_type(&mut self, tp: InputType) -> &mut Self {
+        self.type_input(tp)
+    }
+}
+
 // Input element (void)
 macro_rules! input_items {
     ( $el:literal ) => {
@@ -580,6 +1143,7 @@ macro_rules! input_items {
         html_attr!($el, required, true);
         html_attr!($el, size);
         html_attr!($el, src);
+        url_attr!(src_url, "src");
         html_attr!($el, step);
         html_attr!($el, r#type, "type");
         html_attr!($el, value);
@@ -650,6 +1214,7 @@ macro_rules! link_items {
         html_attr!($el, disabled, true);
         html_attr!($el, fetchpriority);
         html_attr!($el, href);
+        url_attr!(href_url, "href");
         html_attr!($el, hreflang);
         html_attr!($el, imagesize);
         html_attr!($el, imagesrcset);
@@ -657,6 +1222,7 @@ macro_rules! link_items {
         html_attr!($el, media);
         html_attr!($el, referrerpolicy);
         html_attr!($el, rel);
+        rel_tokens!();
         html_attr!($el, sizes);
         html_attr!($el, r#type, "type");
         // NOTE: needed for standalone XML (SVG)
@@ -708,12 +1274,112 @@ macro_rules! menu_items {
 }
 html_elem!("menu", Menu, "Menu", menu_items());
 
+/// [Meta] `charset` attribute value
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Charset {
+    /// `utf-8`: the only value permitted by the HTML spec
+    Utf8,
+}
+
+impl fmt::Display for Charset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<Charset> for Value<'_> {
+    fn from(charset: Charset) -> Self {
+        Value::from(charset.as_str())
+    }
+}
+
+impl Charset {
+    /// Get the attribute value
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Charset::Utf8 => "utf-8",
+        }
+    }
+}
+
+/// [Meta] `name` attribute value
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetaName {
+    /// `application-name`
+    ApplicationName,
+    /// `author`
+    Author,
+    /// `color-scheme`
+    ColorScheme,
+    /// `description`
+    Description,
+    /// `generator`
+    Generator,
+    /// `keywords`
+    Keywords,
+    /// `referrer`
+    Referrer,
+    /// `theme-color`
+    ThemeColor,
+    /// `viewport`
+    Viewport,
+}
+
+impl fmt::Display for MetaName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<MetaName> for Value<'_> {
+    fn from(name: MetaName) -> Self {
+        Value::from(name.as_str())
+    }
+}
+
+impl MetaName {
+    /// Get the attribute value
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            MetaName::ApplicationName => "application-name",
+            MetaName::Author => "author",
+            MetaName::ColorScheme => "color-scheme",
+            MetaName::Description => "description",
+            MetaName::Generator => "generator",
+            MetaName::Keywords => "keywords",
+            MetaName::Referrer => "referrer",
+            MetaName::ThemeColor => "theme-color",
+            MetaName::Viewport => "viewport",
+        }
+    }
+}
+
+impl Meta<'_> {
+    /// Add `charset="utf-8"` attribute
+    pub fn charset_utf8(&mut self) -> &mut Self {
+        self.charset(Charset::Utf8)
+    }
+
+    /// Add `http-equiv="refresh"` and `content` attributes
+    ///
+    /// - `seconds`: Delay before refresh
+    /// - `url`: URL to redirect to, or empty to reload the page
+    pub fn refresh(&mut self, seconds: u32, url: &str) -> &mut Self {
+        self.http_equiv("refresh");
+        if url.is_empty() {
+            self.content(seconds)
+        } else {
+            self.attr_fmt("content", format_args!("{seconds}; url={url}"))
+        }
+    }
+}
+
 // Meta element (void)
 macro_rules! meta_items {
     ( $el:literal ) => {
         html_attr!($el, charset);
         html_attr!($el, content);
-        /* http_equiv, */
+        html_attr!($el, http_equiv, "http-equiv");
         html_attr!($el, media);
         html_attr!($el, name);
         // no content (void)
@@ -922,12 +1588,59 @@ macro_rules! script_items {
         html_attr!($el, nomodule, true);
         html_attr!($el, referrerpolicy);
         html_attr!($el, src);
+        url_attr!(src_url, "src");
         html_attr!($el, r#type, "type");
         cdata_methods!();
     };
 }
 html_elem!("script", Script, "Script", script_items());
 
+impl Script<'_> {
+    /// Add script text
+    ///
+    /// `&`, `<` and `>` are unchanged, but `</script` and `<!--` are
+    /// escaped as `<\/script` and `<\!--`.
+    pub fn script_text<'a, V>(&mut self, text: V) -> &mut Self
+    where
+        V: Into<Value<'a>>,
+    {
+        #[cfg(feature = "validate")]
+        self.tree.check_parent(self.depth, "text");
+        self.tree.raw_text_escaped("script", text);
+        self
+    }
+
+    /// Add JSON script text
+    ///
+    /// The value is serialized, with `<`, `>`, `&`, U+2028 and U+2029
+    /// written as `\u` escapes.
+    ///
+    /// ```rust
+    /// use hatmil::{Tree, html::Script};
+    ///
+    /// let mut tree = Tree::new();
+    /// tree.root::<Script>()
+    ///     .r#type("application/json")
+    ///     .json(&["</script>"])?;
+    /// assert_eq!(
+    ///     String::from(tree),
+    ///     "<script type=\"application/json\">[\"\\u003c/script\\u003e\"]</script>",
+    /// );
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    #[cfg(feature = "serde_json")]
+    pub fn json<T>(&mut self, value: &T) -> serde_json::Result<&mut Self>
+    where
+        T: serde::Serialize + ?Sized,
+    {
+        let json = crate::value::encode_json(value)?;
+        #[cfg(feature = "validate")]
+        self.tree.check_parent(self.depth, "text");
+        self.tree.raw_text_escaped("script", json);
+        Ok(self)
+    }
+}
+
 // Search element
 macro_rules! search_items {
     ( $el:literal ) => {
@@ -970,7 +1683,7 @@ macro_rules! slot_items {
     ( $el:literal ) => {
         html_attr!($el, name);
         // NOTE: transparent content
-        text_content!();
+        flow_content!(abbr, cite, form);
     };
 }
 html_elem!("slot", Slot, "Web Component Slot", slot_items());
@@ -988,6 +1701,7 @@ macro_rules! source_items {
     ( $el:literal ) => {
         html_attr!($el, r#type, "type");
         html_attr!($el, src);
+        url_attr!(src_url, "src");
         html_attr!($el, srcset);
         html_attr!($el, sizes);
         html_attr!($el, media);
@@ -1031,6 +1745,22 @@ macro_rules! style_items {
 }
 html_elem!("style", Style, "Style Information", style_items());
 
+impl Style<'_> {
+    /// Add style sheet text
+    ///
+    /// `&`, `<` and `>` are unchanged, but `</style` and `<!--` are
+    /// escaped as `<\/style` and `<\!--`.
+    pub fn style_text<'a, V>(&mut self, text: V) -> &mut Self
+    where
+        V: Into<Value<'a>>,
+    {
+        #[cfg(feature = "validate")]
+        self.tree.check_parent(self.depth, "text");
+        self.tree.raw_text_escaped("style", text);
+        self
+    }
+}
+
 // Sub element
 macro_rules! sub_items {
     ( $el:literal ) => {
@@ -1104,6 +1834,146 @@ macro_rules! table_items {
 }
 html_elem!("table", Table, "Table", table_items());
 
+/// [Table] builder, with sections in the required order
+///
+/// Sections must be added in this order: caption, column groups, head,
+/// bodies, foot.  In debug builds, adding one out of order panics.
+///
+/// ```rust
+/// use hatmil::{Tree, html::{Table, TableBuilder}};
+///
+/// let mut tree = Tree::new();
+/// let mut table = TableBuilder::new(tree.root::<Table>());
+/// table.caption(|c| {
+///     c.cdata("Totals");
+/// });
+/// table.head(|h| {
+///     h.tr().th().cdata("Item");
+/// });
+/// table.body(|b| {
+///     b.tr().td().cdata("Fish");
+/// });
+/// table.close();
+/// assert_eq!(
+///     String::from(tree),
+///     "<table><caption>Totals</caption>\
+///     <thead><tr><th>Item</th></tr></thead>\
+///     <tbody><tr><td>Fish</td></tr></tbody></table>",
+/// );
+/// ```
+pub struct TableBuilder<'t> {
+    /// Table element
+    table: Table<'t>,
+    /// Most recent section added
+    section: TableSection,
+}
+
+/// Table section, in required order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum TableSection {
+    /// No sections yet
+    Start,
+    /// `caption`
+    Caption,
+    /// `colgroup`
+    ColGroup,
+    /// `thead`
+    Head,
+    /// `tbody`
+    Body,
+    /// `tfoot`
+    Foot,
+}
+
+impl TableSection {
+    /// Get the section tag
+    const fn tag(self) -> &'static str {
+        match self {
+            TableSection::Start => "table",
+            TableSection::Caption => "caption",
+            TableSection::ColGroup => "colgroup",
+            TableSection::Head => "thead",
+            TableSection::Body => "tbody",
+            TableSection::Foot => "tfoot",
+        }
+    }
+}
+
+impl<'t> TableBuilder<'t> {
+    /// Create a table builder
+    pub fn new(table: Table<'t>) -> Self {
+        TableBuilder {
+            table,
+            section: TableSection::Start,
+        }
+    }
+
+    /// Check section order
+    ///
+    /// - `section`: Section to add
+    /// - `repeat`: Section can be repeated
+    fn section(&mut self, section: TableSection, repeat: bool) {
+        debug_assert!(
+            self.section < section || (repeat && self.section == section),
+            "<{}> cannot follow <{}>",
+            section.tag(),
+            self.section.tag()
+        );
+        self.section = section;
+    }
+
+    /// Add `caption` section
+    pub fn caption(&mut self, f: impl FnOnce(&mut Caption)) -> &mut Self {
+        self.section(TableSection::Caption, false);
+        let mut caption = self.table.caption();
+        f(&mut caption);
+        caption.close();
+        self
+    }
+
+    /// Add `colgroup` section
+    pub fn colgroup(&mut self, f: impl FnOnce(&mut ColGroup)) -> &mut Self {
+        self.section(TableSection::ColGroup, true);
+        let mut colgroup = self.table.colgroup();
+        f(&mut colgroup);
+        colgroup.close();
+        self
+    }
+
+    /// Add `thead` section
+    pub fn head(&mut self, f: impl FnOnce(&mut THead)) -> &mut Self {
+        self.section(TableSection::Head, false);
+        let mut thead = self.table.thead();
+        f(&mut thead);
+        thead.close();
+        self
+    }
+
+    /// Add `tbody` section
+    pub fn body(&mut self, f: impl FnOnce(&mut TBody)) -> &mut Self {
+        self.section(TableSection::Body, true);
+        let mut tbody = self.table.tbody();
+        f(&mut tbody);
+        tbody.close();
+        self
+    }
+
+    /// Add `tfoot` section
+    pub fn foot(&mut self, f: impl FnOnce(&mut TFoot)) -> &mut Self {
+        self.section(TableSection::Foot, false);
+        let mut tfoot = self.table.tfoot();
+        f(&mut tfoot);
+        tfoot.close();
+        self
+    }
+
+    /// Close the table element
+    pub fn close(self) -> &'t mut Tree {
+        let Table { tree, depth } = self.table;
+        tree.close_to(depth)
+    }
+}
+
 // Td element
 macro_rules! td_items {
     ( $el:literal ) => {
@@ -1172,6 +2042,21 @@ macro_rules! time_items {
 }
 html_elem!("time", Time, "Time / Date", time_items());
 
+#[cfg(any(feature = "chrono", feature = "time"))]
+impl Time<'_> {
+    /// Add `datetime` attribute from a date or date and time
+    ///
+    /// See [DateTimeIso] for supported types.
+    ///
+    /// [DateTimeIso]: crate::DateTimeIso
+    pub fn datetime_iso<V>(&mut self, val: V) -> &mut Self
+    where
+        V: crate::DateTimeIso,
+    {
+        self.datetime(val)
+    }
+}
+
 // Title element
 macro_rules! title_content {
     ( $el:literal ) => {
@@ -1199,6 +2084,7 @@ macro_rules! track_items {
         html_attr!($el, kind);
         html_attr!($el, label);
         html_attr!($el, src);
+        url_attr!(src_url, "src");
         html_attr!($el, srclang);
         // no content (void)
     };
@@ -1254,6 +2140,7 @@ macro_rules! video_items {
         html_attr!($el, poster);
         html_attr!($el, preload);
         html_attr!($el, src);
+        url_attr!(src_url, "src");
         html_attr!($el, width);
         // NOTE: special content rules
         elem_method!(source, Source);
@@ -1277,3 +2164,455 @@ html_elem!(
     wbr_items(),
     ElemType::HtmlVoid
 );
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::AriaAttrs;
+
+    #[test]
+    fn charset() {
+        let mut tree = Tree::new();
+        let mut head = tree.root::<Head>();
+        head.meta().charset_utf8().close();
+        head.meta().charset(Charset::Utf8).close();
+        assert_eq!(
+            tree.to_string(),
+            "<head><meta charset=\"utf-8\"><meta charset=\"utf-8\"></head>"
+        );
+    }
+
+    #[test]
+    fn meta_name() {
+        let mut tree = Tree::new();
+        let mut head = tree.root::<Head>();
+        head.meta()
+            .name(MetaName::ColorScheme)
+            .content("light dark")
+            .close();
+        head.meta().name("custom").content("value").close();
+        assert_eq!(
+            tree.to_string(),
+            "<head><meta name=\"color-scheme\" content=\"light dark\">\
+            <meta name=\"custom\" content=\"value\"></head>"
+        );
+    }
+
+    #[test]
+    fn table_builder() {
+        let mut tree = Tree::new();
+        let mut table = TableBuilder::new(tree.root::<Table>());
+        table.colgroup(|c| {
+            c.span(2);
+        });
+        table.head(|h| {
+            let mut tr = h.tr();
+            tr.th().cdata("A").close();
+            tr.th().cdata("B");
+        });
+        for row in [["1", "2"], ["3", "4"]] {
+            table.body(|b| {
+                let mut tr = b.tr();
+                for cell in row {
+                    tr.td().cdata(cell).close();
+                }
+            });
+        }
+        table.foot(|f| {
+            f.tr().td().colspan(2).cdata("Sum");
+        });
+        table.close();
+        assert_eq!(
+            String::from(tree),
+            "<table><colgroup span=\"2\"></colgroup>\
+            <thead><tr><th>A</th><th>B</th></tr></thead>\
+            <tbody><tr><td>1</td><td>2</td></tr></tbody>\
+            <tbody><tr><td>3</td><td>4</td></tr></tbody>\
+            <tfoot><tr><td colspan=\"2\">Sum</td></tr></tfoot></table>"
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "<thead> cannot follow <tfoot>")]
+    fn table_builder_order() {
+        let mut tree = Tree::new();
+        let mut table = TableBuilder::new(tree.root::<Table>());
+        table.foot(|_| ());
+        table.head(|_| ());
+    }
+
+    #[test]
+    fn rel_tokens() {
+        let mut tree = Tree::new();
+        let mut body = tree.root::<Body>();
+        body.a()
+            .href("https://example.com/")
+            .rel_tokens([Rel::NoOpener, Rel::NoReferrer, Rel::NoOpener])
+            .cdata("Ext")
+            .close();
+        body.a().rel_tokens([Rel::External, Rel::NoFollow]).close();
+        body.form().rel_tokens(vec![Rel::Search]).close();
+        body.a().rel("me noopner");
+        assert_eq!(
+            String::from(tree),
+            "<body><a href=\"https://example.com/\" rel=\"noopener noreferrer\">Ext</a>\
+            <a rel=\"external nofollow\"></a>\
+            <form rel=\"search\"></form>\
+            <a rel=\"me noopner\"></a></body>"
+        );
+        let mut tree = Tree::new();
+        tree.root::<Link>()
+            .rel_tokens([Rel::Preload, Rel::Icon])
+            .r#as("image");
+        assert_eq!(
+            String::from(tree),
+            "<link rel=\"preload icon\" as=\"image\" />"
+        );
+        assert_eq!(Rel::DnsPrefetch.to_string(), "dns-prefetch");
+    }
+
+    #[test]
+    fn rel_list() {
+        let mut tree = Tree::new();
+        let mut head = tree.root::<Head>();
+        head.link().rel(Rel::Stylesheet).href("a.css").close();
+        head.link()
+            .rel(Rel::Alternate + Rel::Next + Rel::Alternate)
+            .close();
+        head.link().rel(Rel::Icon.and(Rel::Prefetch)).close();
+        let mut list = RelList::new();
+        assert!(list.is_empty());
+        list.push(Rel::Me).push(Rel::Prev);
+        head.link().rel(list);
+        assert_eq!(
+            String::from(tree),
+            "<head><link rel=\"stylesheet\" href=\"a.css\" />\
+            <link rel=\"alternate next\" />\
+            <link rel=\"icon prefetch\" />\
+            <link rel=\"me prev\" /></head>"
+        );
+    }
+
+    #[test]
+    fn head_links() {
+        let mut tree = Tree::new();
+        let mut head = tree.root::<Head>();
+        head.stylesheet("a.css").close();
+        head.stylesheet("print.css").media("print").close();
+        head.icon("favicon.png").r#type("image/png").close();
+        head.icon("favicon.ico").close();
+        head.preload("font.woff2", "font")
+            .r#type("font/woff2")
+            .crossorigin("")
+            .close();
+        head.stylesheet("b.css").integrity("sha384-x&y");
+        assert_eq!(
+            tree.to_string(),
+            "<head><link rel=\"stylesheet\" href=\"a.css\" />\
+            <link rel=\"stylesheet\" href=\"print.css\" media=\"print\" />\
+            <link rel=\"icon\" href=\"favicon.png\" type=\"image/png\" />\
+            <link rel=\"icon\" href=\"favicon.ico\" />\
+            <link rel=\"preload\" href=\"font.woff2\" as=\"font\" \
+            type=\"font/woff2\" crossorigin=\"\" />\
+            <link rel=\"stylesheet\" href=\"b.css\" integrity=\"sha384-x&amp;y\" /></head>"
+        );
+    }
+
+    #[test]
+    fn head_helpers() {
+        let mut tree = Tree::new();
+        let mut head = tree.root::<Head>();
+        head.charset_utf8()
+            .viewport(Head::VIEWPORT)
+            .title_el()
+            .cdata("Fish & Chips")
+            .close();
+        head.description("Fish \"&\" chips")
+            .canonical("https://example.com/?a=1&b=2");
+        let typical = tree.to_string();
+        let mut tree = Tree::new();
+        let mut head = tree.root::<Head>();
+        head.meta().charset("utf-8").close();
+        head.meta()
+            .name("viewport")
+            .content("width=device-width, initial-scale=1")
+            .close();
+        head.title_el().cdata("Fish & Chips").close();
+        head.meta()
+            .name("description")
+            .content("Fish \"&\" chips")
+            .close();
+        head.link()
+            .rel("canonical")
+            .href("https://example.com/?a=1&b=2")
+            .close();
+        assert_eq!(typical, tree.to_string());
+        assert_eq!(
+            typical,
+            "<head><meta charset=\"utf-8\">\
+            <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\
+            <title>Fish &amp; Chips</title>\
+            <meta name=\"description\" content=\"Fish &quot;&amp;&quot; chips\">\
+            <link rel=\"canonical\" href=\"https://example.com/?a=1&amp;b=2\" /></head>"
+        );
+    }
+
+    #[test]
+    fn script_text() {
+        let mut tree = Tree::new();
+        let mut head = tree.root::<Head>();
+        head.script()
+            .script_text("if (a && b < c) { s = '</SCRIPT><!-- x'; }")
+            .close();
+        head.style_el()
+            .style_text("p::after { content: '</style>'; }");
+        assert_eq!(
+            String::from(tree),
+            "<head><script>if (a && b < c) { s = '<\\/SCRIPT><\\!-- x'; }</script>\
+            <style>p::after { content: '<\\/style>'; }</style></head>"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde_json")]
+    fn script_json() {
+        let value = serde_json::json!({
+            "text": "</script><!-- a & b > c",
+            "lines": "one\u{2028}two\u{2029}three",
+        });
+        let mut tree = Tree::new();
+        let mut body = tree.root::<Body>();
+        body.script()
+            .r#type("application/json")
+            .json(&value)
+            .unwrap()
+            .close();
+        tree.json_script("data", &value).unwrap();
+        let script = "{\"lines\":\"one\\u2028two\\u2029three\",\
+            \"text\":\"\\u003c/script\\u003e\\u003c!-- a \\u0026 b \\u003e c\"}";
+        assert_eq!(
+            String::from(tree),
+            format!(
+                "<body><script type=\"application/json\">{script}</script>\
+                <script type=\"application/json\" id=\"data\">{script}</script>\
+                </body>"
+            )
+        );
+        let parsed: serde_json::Value = serde_json::from_str(script).unwrap();
+        assert_eq!(parsed, value);
+    }
+
+    /// Pixel length newtype
+    struct Px(u32);
+
+    impl fmt::Display for Px {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    #[test]
+    fn from_display() {
+        let mut tree = Tree::new();
+        tree.root::<Img>()
+            .width(Value::from_display(Px(100)))
+            .height(Value::from_display(Px(50)));
+        assert_eq!(tree.to_string(), "<img width=\"100\" height=\"50\">");
+    }
+
+    #[test]
+    fn aria() {
+        let mut tree = Tree::new();
+        tree.root::<Button>()
+            .aria_label("Close")
+            .aria_expanded(false)
+            .cdata("X");
+        assert_eq!(
+            tree.to_string(),
+            "<button aria-label=\"Close\" aria-expanded=\"false\">X</button>"
+        );
+        let mut tree = Tree::new();
+        tree.root::<Div>()
+            .role("checkbox")
+            .aria_checked("mixed")
+            .aria_hidden(true)
+            .aria_describedby("hint")
+            .aria_live("polite");
+        assert_eq!(
+            tree.to_string(),
+            "<div role=\"checkbox\" aria-checked=\"mixed\" aria-hidden=\"true\" \
+            aria-describedby=\"hint\" aria-live=\"polite\"></div>"
+        );
+        let mut tree = Tree::new();
+        tree.root::<Span>().aria_role("status").aria_hidden(false);
+        assert_eq!(
+            tree.to_string(),
+            "<span role=\"status\" aria-hidden=\"false\"></span>"
+        );
+    }
+
+    #[test]
+    fn event_handlers() {
+        let mut tree = Tree::new();
+        let mut form = tree.root::<Form>();
+        form.onsubmit("return check()");
+        form.button().onclick("handleClick()").cdata("Go").close();
+        form.input().oninput("update()").onchange("save()").close();
+        form.div().onkeydown("key(event)").onmouseenter("hover()");
+        assert_eq!(
+            tree.to_string(),
+            "<form onsubmit=\"return check()\">\
+            <button onclick=\"handleClick()\">Go</button>\
+            <input oninput=\"update()\" onchange=\"save()\">\
+            <div onkeydown=\"key(event)\" onmouseenter=\"hover()\"></div></form>"
+        );
+    }
+
+    #[test]
+    fn slot() {
+        let mut tree = Tree::new();
+        let mut slot = tree.root::<Slot>();
+        slot.p().cdata("Fallback text");
+        assert_eq!(tree.to_string(), "<slot><p>Fallback text</p></slot>");
+    }
+
+    #[test]
+    fn input_type() {
+        let mut tree = Tree::new();
+        let mut form = tree.root::<Form>();
+        form.input().type_input(InputType::Email).close();
+        form.input().input_type(InputType::Number).close();
+        form.input().r#type(InputType::DatetimeLocal).close();
+        assert_eq!(
+            tree.to_string(),
+            "<form><input type=\"email\"><input type=\"number\">\
+            <input type=\"datetime-local\"></form>"
+        );
+        assert_eq!(InputType::Checkbox.to_string(), "checkbox");
+    }
+
+    #[test]
+    fn button_type() {
+        let mut tree = Tree::new();
+        let mut form = tree.root::<Form>();
+        form.button()
+            .type_btn(ButtonType::Button)
+            .cdata("Preview")
+            .close();
+        form.button()
+            .button_type(ButtonType::Submit)
+            .cdata("Send")
+            .close();
+        assert_eq!(
+            tree.to_string(),
+            "<form><button type=\"button\">Preview</button>\
+            <button type=\"submit\">Send</button></form>"
+        );
+    }
+
+    #[test]
+    fn event_escaping() {
+        let mut tree = Tree::new();
+        let mut body = tree.root::<Body>();
+        body.onload("init()").onbeforeprint("prep()");
+        body.button().onclick("doThing(1)").close();
+        body.button()
+            .onclick("say(\"hi\" & 'bye')")
+            .ondblclick("x()");
+        assert_eq!(
+            tree.to_string(),
+            "<body onload=\"init()\" onbeforeprint=\"prep()\">\
+            <button onclick=\"doThing(1)\"></button>\
+            <button onclick=\"say(&quot;hi&quot; &amp; 'bye')\" ondblclick=\"x()\">\
+            </button></body>"
+        );
+    }
+
+    #[test]
+    fn http_equiv() {
+        let mut tree = Tree::new();
+        let mut head = tree.root::<Head>();
+        head.meta()
+            .http_equiv("content-security-policy")
+            .content("default-src 'self'")
+            .close();
+        head.meta().refresh(30, "").close();
+        head.meta().refresh(0, "/next?a=1&b=\"2\"").close();
+        assert_eq!(
+            tree.to_string(),
+            "<head><meta http-equiv=\"content-security-policy\" \
+            content=\"default-src 'self'\">\
+            <meta http-equiv=\"refresh\" content=\"30\">\
+            <meta http-equiv=\"refresh\" \
+            content=\"0; url=/next?a=1&amp;b=&quot;2&quot;\"></head>"
+        );
+    }
+
+    #[test]
+    fn fencedframe() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.fencedframe()
+            .allow("attribution-reporting")
+            .width(300)
+            .height(250);
+        assert_eq!(
+            tree.to_string(),
+            "<div><fencedframe allow=\"attribution-reporting\" width=\"300\" \
+            height=\"250\"></fencedframe></div>"
+        );
+    }
+
+    #[test]
+    fn extend() {
+        let mut tree = Tree::new();
+        tree.root::<Ul>().extend(["a", "b", "c"], |ul, s| {
+            ul.li().cdata(s).close();
+        });
+        assert_eq!(tree.to_string(), "<ul><li>a</li><li>b</li><li>c</li></ul>");
+        let mut tree = Tree::new();
+        tree.extend(1..=2, |tree, i| {
+            tree.root::<P>().cdata(i).close();
+        });
+        assert_eq!(tree.to_string(), "<p>1</p><p>2</p>");
+    }
+
+    #[test]
+    fn typed_types() {
+        for (tp, val) in [
+            (InputType::Text, "text"),
+            (InputType::Password, "password"),
+            (InputType::Email, "email"),
+            (InputType::Number, "number"),
+            (InputType::Checkbox, "checkbox"),
+            (InputType::Radio, "radio"),
+            (InputType::Date, "date"),
+            (InputType::File, "file"),
+            (InputType::Hidden, "hidden"),
+            (InputType::Submit, "submit"),
+            (InputType::Range, "range"),
+            (InputType::Color, "color"),
+        ] {
+            let mut tree = Tree::new();
+            tree.root::<Input>().type_input(tp);
+            assert_eq!(tree.to_string(), format!("<input type=\"{val}\">"));
+        }
+        for (tp, val) in [
+            (ButtonType::Submit, "submit"),
+            (ButtonType::Reset, "reset"),
+            (ButtonType::Button, "button"),
+        ] {
+            let mut tree = Tree::new();
+            tree.root::<Button>().type_btn(tp);
+            let html = format!("<button type=\"{val}\"></button>");
+            assert_eq!(tree.to_string(), html);
+            let mut tree = Tree::new();
+            tree.root::<Button>().r#type(val);
+            assert_eq!(tree.to_string(), html);
+        }
+        let mut tree = Tree::new();
+        tree.root::<Input>().r#type("week");
+        assert_eq!(tree.to_string(), "<input type=\"week\">");
+    }
+}
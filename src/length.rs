@@ -0,0 +1,149 @@
+// length.rs
+//
+// Copyright (C) 2026  Douglas P Lau
+//
+use crate::value::{SvgNum, Value};
+use std::fmt;
+
+/// SVG length unit
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SvgUnit {
+    /// Pixels (`px`)
+    Px,
+    /// Font size (`em`)
+    Em,
+    /// Root element font size (`rem`)
+    Rem,
+    /// Percentage (`%`)
+    Percent,
+    /// Points (`pt`)
+    Pt,
+    /// Centimeters (`cm`)
+    Cm,
+    /// Millimeters (`mm`)
+    Mm,
+}
+
+/// SVG length with a unit
+///
+/// Unitless lengths can be given as plain numbers.  Lengths are written with
+/// up to 4 decimal places, trimming trailing zeros.
+///
+/// ```rust
+/// use hatmil::{SvgLength, Tree, svg::Circle};
+///
+/// let mut tree = Tree::new();
+/// tree.root::<Circle>().cx(SvgLength::pct(50.0)).cy(20).r(SvgLength::px(10.0));
+/// assert_eq!(
+///     String::from(tree),
+///     "<circle cx=\"50%\" cy=\"20\" r=\"10px\" />",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SvgLength(pub f64, pub SvgUnit);
+
+impl SvgUnit {
+    /// Get unit suffix
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SvgUnit::Px => "px",
+            SvgUnit::Em => "em",
+            SvgUnit::Rem => "rem",
+            SvgUnit::Percent => "%",
+            SvgUnit::Pt => "pt",
+            SvgUnit::Cm => "cm",
+            SvgUnit::Mm => "mm",
+        }
+    }
+}
+
+impl fmt::Display for SvgUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl fmt::Display for SvgLength {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", SvgNum(self.0), self.1)
+    }
+}
+
+impl From<SvgLength> for Value<'_> {
+    fn from(len: SvgLength) -> Self {
+        Value::from(len.to_string())
+    }
+}
+
+impl SvgLength {
+    /// Create a length in pixels
+    pub fn px(v: f64) -> Self {
+        SvgLength(v, SvgUnit::Px)
+    }
+
+    /// Create a length in `em` units
+    pub fn em(v: f64) -> Self {
+        SvgLength(v, SvgUnit::Em)
+    }
+
+    /// Create a length in `rem` units
+    pub fn rem(v: f64) -> Self {
+        SvgLength(v, SvgUnit::Rem)
+    }
+
+    /// Create a percentage length
+    pub fn pct(v: f64) -> Self {
+        SvgLength(v, SvgUnit::Percent)
+    }
+
+    /// Create a length in points
+    pub fn pt(v: f64) -> Self {
+        SvgLength(v, SvgUnit::Pt)
+    }
+
+    /// Create a length in centimeters
+    pub fn cm(v: f64) -> Self {
+        SvgLength(v, SvgUnit::Cm)
+    }
+
+    /// Create a length in millimeters
+    pub fn mm(v: f64) -> Self {
+        SvgLength(v, SvgUnit::Mm)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Tree;
+    use crate::svg::Svg;
+
+    #[test]
+    fn units() {
+        assert_eq!(SvgLength::px(10.0).to_string(), "10px");
+        assert_eq!(SvgLength::em(1.5).to_string(), "1.5em");
+        assert_eq!(SvgLength::rem(2.0).to_string(), "2rem");
+        assert_eq!(SvgLength::pct(-25.0).to_string(), "-25%");
+        assert_eq!(SvgLength::pt(12.0).to_string(), "12pt");
+        assert_eq!(SvgLength::cm(0.5).to_string(), "0.5cm");
+        assert_eq!(SvgLength(3.0, SvgUnit::Mm).to_string(), "3mm");
+        assert_eq!(SvgLength::px(0.1 + 0.2).to_string(), "0.3px");
+        assert_eq!(SvgLength::pct(100.0 / 3.0).to_string(), "33.3333%");
+        assert_eq!(SvgLength::em(-0.00001).to_string(), "0em");
+    }
+
+    #[test]
+    fn svg() {
+        let mut tree = Tree::new();
+        let mut svg = tree.root::<Svg>();
+        svg.width(SvgLength::pct(100.0)).height(SvgLength::em(4.0));
+        svg.circle().r(SvgLength::px(10.0)).close();
+        svg.rect().x(5).width(10).height(SvgLength::mm(2.5));
+        assert_eq!(
+            String::from(tree),
+            "<svg width=\"100%\" height=\"4em\">\
+            <circle r=\"10px\" />\
+            <rect x=\"5\" width=\"10\" height=\"2.5mm\" /></svg>"
+        );
+    }
+}
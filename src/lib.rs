@@ -7,17 +7,45 @@
 #[macro_use]
 mod macros;
 
+mod aria;
+mod aspect;
+mod class;
+#[cfg(feature = "codegen")]
+pub mod codegen;
+mod color;
 mod definition;
+mod error;
+mod fragment;
+mod href;
 pub mod html;
+mod length;
 mod poly;
+mod style;
 pub mod svg;
+mod transform;
 mod tree;
+#[cfg(feature = "validate")]
+mod validate;
 mod value;
+mod viewbox;
 
 #[allow(deprecated)]
 pub use tree::Page;
 
+pub use aria::AriaAttrs;
+pub use aspect::{Align, MeetOrSlice, PreserveAspectRatio};
+pub use class::ClassList;
+pub use color::SvgColor;
 pub use definition::PathDefBuilder;
+pub use error::Error;
+pub use fragment::Fragment;
+pub use href::SafeHref;
+pub use length::{SvgLength, SvgUnit};
 pub use poly::PolyPointBuilder;
-pub use tree::Tree;
-pub use value::Value;
+pub use style::{Css, CssRule, StyleAttr};
+pub use transform::{SvgTransformList, Transform, TransformList};
+pub use tree::{Doctype, Tree};
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub use value::DateTimeIso;
+pub use value::{ControlCharPolicy, EntityStyle, EscapeProfile, Value};
+pub use viewbox::ViewBox;
@@ -2,6 +2,168 @@
 //
 // Copyright (C) 2025-2026  Douglas P Lau
 
+/// Add formatted character data content to an element
+///
+/// ```rust
+/// use hatmil::{Tree, cdata_fmt, html::P};
+///
+/// let mut tree = Tree::new();
+/// let mut p = tree.root::<P>();
+/// cdata_fmt!(p, "{} < {}", 1, 2);
+/// assert_eq!(String::from(tree), "<p>1 &lt; 2</p>");
+/// ```
+#[macro_export]
+macro_rules! cdata_fmt {
+    ($elem:expr, $($arg:tt)*) => {
+        $elem.cdata_fmt(format_args!($($arg)*))
+    };
+}
+
+/// Conditional and iterator builder methods
+macro_rules! cond_methods {
+    () => {
+        /// Build content only if a condition is true
+        ///
+        /// - `condition`: Condition to check
+        /// - `f`: Closure to call with the element
+        pub fn if_elem<F>(&mut self, condition: bool, f: F) -> &mut Self
+        where
+            F: FnOnce(&mut Self),
+        {
+            if condition {
+                f(self);
+            }
+            self
+        }
+
+        /// Build content depending on a condition
+        ///
+        /// - `condition`: Condition to check
+        /// - `then`: Closure to call with the element if `condition` is true
+        /// - `else_`: Closure to call with the element otherwise
+        pub fn if_else_elem<F, G>(
+            &mut self,
+            condition: bool,
+            then: F,
+            else_: G,
+        ) -> &mut Self
+        where
+            F: FnOnce(&mut Self),
+            G: FnOnce(&mut Self),
+        {
+            if condition {
+                then(self);
+            } else {
+                else_(self);
+            }
+            self
+        }
+
+        /// Build content for each item of an iterator
+        ///
+        /// - `iter`: Items to build content from
+        /// - `f`: Closure to call with the element and each item
+        pub fn extend<I, F>(&mut self, iter: I, mut f: F) -> &mut Self
+        where
+            I: IntoIterator,
+            F: FnMut(&mut Self, I::Item),
+        {
+            for item in iter {
+                f(self, item);
+            }
+            self
+        }
+    };
+}
+
+/// Collect element item methods into a code generation table
+///
+/// Method macros are shadowed within the invoking function, so that each
+/// item adds a table entry instead of a method.
+#[cfg(feature = "codegen")]
+macro_rules! codegen_table {
+    ( $info:ident, $( $items:ident!( $( $args:tt )* ); )* ) => {
+        use $crate::codegen::AttrKind;
+        #[allow(unused_macros)]
+        macro_rules! elem_method {
+            ( $meth:ident, $elem:ident ) => {
+                $info.child(
+                    stringify!($meth),
+                    $elem::CODEGEN_SVG,
+                    $elem::TAG,
+                    $elem::codegen,
+                );
+            };
+        }
+        #[allow(unused_macros)]
+        macro_rules! val_attr {
+            ( $path:expr, $attr:ident, $raw_attr:expr ) => {
+                $info.attr($raw_attr, stringify!($attr), AttrKind::Value);
+            };
+        }
+        #[allow(unused_macros)]
+        macro_rules! bool_attr {
+            ( $path:expr, $attr:ident, $raw_attr:expr ) => {
+                $info.attr($raw_attr, stringify!($attr), AttrKind::Boolean);
+            };
+        }
+        #[allow(unused_macros)]
+        macro_rules! aria_attribute {
+            ( $attr:ident, $raw_attr:literal ) => {
+                $info.attr($raw_attr, stringify!($attr), AttrKind::Value);
+            };
+            ( $attr:ident, $raw_attr:literal, bool ) => {
+                $info.attr($raw_attr, stringify!($attr), AttrKind::TrueFalse);
+            };
+        }
+        #[allow(unused_macros)]
+        macro_rules! event_attr {
+            ( $attr:ident ) => {
+                let attr = stringify!($attr);
+                $info.attr(attr, attr, AttrKind::Value);
+            };
+        }
+        #[allow(unused_macros)]
+        macro_rules! transform_methods {
+            () => {
+                $info.attr("transform", "transform", AttrKind::Value);
+            };
+        }
+        #[allow(unused_macros)]
+        macro_rules! custom_method {
+            () => {
+                $info.custom();
+            };
+        }
+        // methods which are not generated from markup
+        #[allow(unused_macros)]
+        macro_rules! url_attr {
+            ( $method:ident, $raw_attr:literal ) => {};
+        }
+        #[allow(unused_macros)]
+        macro_rules! data_attribute {
+            () => {};
+        }
+        #[allow(unused_macros)]
+        macro_rules! try_attr_method {
+            () => {};
+        }
+        #[allow(unused_macros)]
+        macro_rules! cdata_methods {
+            () => {};
+        }
+        #[allow(unused_macros)]
+        macro_rules! comment_raw_methods {
+            () => {};
+        }
+        #[allow(unused_macros)]
+        macro_rules! rel_tokens {
+            () => {};
+        }
+        $( $items!( $( $args )* ); )*
+    };
+}
+
 /// Create an HTML element
 #[rustfmt::skip]
 macro_rules! html_elem {
@@ -44,18 +206,60 @@ macro_rules! html_elem {
                 self.tree.close_to(self.depth);
                 self.tree
             }
+
+            #[doc = "Close the element, checking that no child elements are open"]
+            #[doc = ""]
+            #[doc = concat!(
+                "Returns [Error::MismatchedClose](crate::Error::MismatchedClose)",
+                " if a child element is open, or ",
+                "[Error::UnbalancedClose](crate::Error::UnbalancedClose)",
+                " if the element was already closed.",
+            )]
+            pub fn try_end(
+                &'t mut self,
+            ) -> Result<&'t mut Tree, $crate::Error> {
+                self.tree.try_close_to(self.depth)?;
+                Ok(self.tree)
+            }
+
+            #[doc = "Reserve capacity for additional document text, in bytes"]
+            pub fn reserve(&mut self, additional: usize) -> &mut Self {
+                self.tree.reserve(additional);
+                self
+            }
+
+            cond_methods!();
         }
 
-        #[doc = "Global attributes"]
-        impl<'t> $elem<'t> {
-            global_attributes!();
+        global_attributes!($elem);
+
+        #[cfg(feature = "codegen")]
+        impl $elem<'_> {
+            /// SVG element, for code generation
+            pub(crate) const CODEGEN_SVG: bool = false;
+
+            /// Get element methods, for code generation
+            pub(crate) fn codegen() -> $crate::codegen::ElemInfo {
+                let mut info = $crate::codegen::ElemInfo::new(
+                    Self::CODEGEN_SVG,
+                    $el,
+                    stringify!($elem),
+                );
+                codegen_table!(
+                    info,
+                    $items!($el);
+                    global_attributes!();
+                    aria_attributes!();
+                );
+                info
+            }
         }
 
         impl<'t> Element<'t> for $elem<'t> {
             const TAG: &'static str = $el;
             const TP: ElemType = $tp;
-            fn new(tree: &'t mut Tree) -> Self {
-                $elem { tree, depth: 1 }
+            fn new(tree: &'t mut Tree, depth: usize) -> Self {
+                $elem { tree, depth }
             }
         }
     };
@@ -78,12 +282,30 @@ macro_rules! val_attr {
         where
             V: Into<Value<'a>>,
         {
-            self.tree.attr($raw_attr, val);
+            self.tree.attr(self.depth, $raw_attr, val);
             self
         }
     };
 }
 
+/// Make a URL attribute method (`url` feature)
+macro_rules! url_attr {
+    ( $method:ident, $raw_attr:literal ) => {
+        #[doc = concat!("Add `", $raw_attr, "` attribute from a URL")]
+        ///
+        /// Returns an error for `javascript:` URLs.  To allow them, pass the
+        /// URL to the plain attribute method instead.
+        #[cfg(feature = "url")]
+        pub fn $method(
+            &mut self,
+            url: &::url::Url,
+        ) -> Result<&mut Self, crate::Error> {
+            self.tree.try_attr_url(self.depth, $raw_attr, url)?;
+            Ok(self)
+        }
+    };
+}
+
 /// Make an HTML Boolean attribute method
 #[rustfmt::skip]
 macro_rules! bool_attr {
@@ -98,7 +320,7 @@ macro_rules! bool_attr {
             ") Boolean attribute",
         )]
         pub fn $attr(&mut self) -> &mut Self {
-            self.tree.attr_bool($raw_attr);
+            self.tree.attr_bool(self.depth, $raw_attr);
             self
         }
     };
@@ -164,15 +386,44 @@ macro_rules! global_attribute {
     };
 }
 
-/// Make a global ARIA attribute method
+/// Make an ARIA attribute method, for the `AriaAttrs` trait
+#[rustfmt::skip]
 macro_rules! aria_attribute {
-    // Make a global ARIA "value" attribute
+    // Make an ARIA "value" attribute
     ( $attr:ident, $raw_attr:literal ) => {
-        val_attr!(
-            concat!("Web/Accessibility/ARIA/Reference/Attributes/"),
-            $attr,
-            $raw_attr
-        );
+        #[doc = concat!(
+            "Add [",
+            $raw_attr,
+            "](",
+            "https://developer.mozilla.org/en-US/docs/",
+            "Web/Accessibility/ARIA/Reference/Attributes/",
+            $raw_attr,
+            ") attribute",
+        )]
+        fn $attr<'a, V>(&mut self, val: V) -> &mut Self
+        where
+            V: Into<Value<'a>>,
+        {
+            self.aria_attr($raw_attr, val.into());
+            self
+        }
+    };
+
+    // Make an ARIA `true` / `false` attribute
+    ( $attr:ident, $raw_attr:literal, bool ) => {
+        #[doc = concat!(
+            "Add [",
+            $raw_attr,
+            "](",
+            "https://developer.mozilla.org/en-US/docs/",
+            "Web/Accessibility/ARIA/Reference/Attributes/",
+            $raw_attr,
+            ") attribute (`true` or `false`)",
+        )]
+        fn $attr(&mut self, val: bool) -> &mut Self {
+            self.aria_attr($raw_attr, val.into());
+            self
+        }
     };
 }
 
@@ -181,22 +432,195 @@ macro_rules! data_attribute {
     () => {
         /// Add [data-*] attribute
         ///
-        /// `name` should contain only lower-case letters and hyphens.
+        /// `name` must contain only lower-case ASCII letters, digits and
+        /// hyphens; panics otherwise.
         ///
         /// [data-*]: https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/data-*
         pub fn data_<'a, V>(&mut self, name: &str, val: V) -> &mut Self
         where
             V: Into<Value<'a>>,
         {
-            self.tree.attr(&format!("data-{name}"), val);
+            if let Err(e) = self.tree.try_attr_data(self.depth, name, val) {
+                panic!("{e}");
+            }
+            self
+        }
+
+        /// Add [data-*] attribute, checking for errors
+        ///
+        /// Returns an error if `name` is not valid, or if the element already
+        /// has content.
+        ///
+        /// [data-*]: https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/data-*
+        pub fn try_data_<'a, V>(
+            &mut self,
+            name: &str,
+            val: V,
+        ) -> Result<&mut Self, crate::Error>
+        where
+            V: Into<Value<'a>>,
+        {
+            self.tree.try_attr_data(self.depth, name, val)?;
+            Ok(self)
+        }
+    };
+}
+
+/// Make a checked attribute method, for any attribute name
+macro_rules! try_attr_method {
+    () => {
+        /// Add an attribute by name, checking for errors
+        ///
+        /// - `attr`: Attribute name
+        /// - `val`: Attribute value
+        ///
+        /// Returns an error if `attr` is not a valid attribute name, or if
+        /// the element already has content.
+        pub fn try_attr<'a, V>(
+            &mut self,
+            attr: &str,
+            val: V,
+        ) -> Result<&mut Self, crate::Error>
+        where
+            V: Into<Value<'a>>,
+        {
+            self.tree.try_attr(self.depth, attr, val)?;
+            Ok(self)
+        }
+
+        /// Add an attribute by name, with a formatted value
+        ///
+        /// - `attr`: Attribute name
+        /// - `args`: Value arguments, from [format_args]
+        ///
+        /// The value is escaped while it is formatted.  Panics if `attr` is
+        /// not a valid attribute name, or if the element already has
+        /// content.
+        pub fn attr_fmt(
+            &mut self,
+            attr: &str,
+            args: std::fmt::Arguments,
+        ) -> &mut Self {
+            if let Err(e) = self.tree.try_attr_fmt(self.depth, attr, args) {
+                panic!("{e}");
+            }
             self
         }
     };
 }
 
+/// Make an event handler attribute method
+macro_rules! event_attr {
+    ( $attr:ident ) => {
+        #[doc = concat!("Add `", stringify!($attr), "` event handler attribute")]
+        ///
+        /// **WARNING**: the value is script, which is escaped as an attribute
+        /// value but otherwise used verbatim; do not call with untrusted
+        /// content.
+        pub fn $attr<'a, V>(&mut self, val: V) -> &mut Self
+        where
+            V: Into<Value<'a>>,
+        {
+            self.tree.attr(self.depth, stringify!($attr), val);
+            self
+        }
+    };
+}
+
+/// Global event handler attributes
+macro_rules! global_event_attributes {
+    () => {
+        event_attr!(onabort);
+        event_attr!(onauxclick);
+        event_attr!(onbeforeinput);
+        event_attr!(onbeforematch);
+        event_attr!(onbeforetoggle);
+        event_attr!(onblur);
+        event_attr!(oncancel);
+        event_attr!(oncanplay);
+        event_attr!(oncanplaythrough);
+        event_attr!(onchange);
+        event_attr!(onclick);
+        event_attr!(onclose);
+        event_attr!(oncontextlost);
+        event_attr!(oncontextmenu);
+        event_attr!(oncontextrestored);
+        event_attr!(oncopy);
+        event_attr!(oncuechange);
+        event_attr!(oncut);
+        event_attr!(ondblclick);
+        event_attr!(ondrag);
+        event_attr!(ondragend);
+        event_attr!(ondragenter);
+        event_attr!(ondragleave);
+        event_attr!(ondragover);
+        event_attr!(ondragstart);
+        event_attr!(ondrop);
+        event_attr!(ondurationchange);
+        event_attr!(onemptied);
+        event_attr!(onended);
+        event_attr!(onerror);
+        event_attr!(onfocus);
+        event_attr!(onformdata);
+        event_attr!(oninput);
+        event_attr!(oninvalid);
+        event_attr!(onkeydown);
+        event_attr!(onkeypress);
+        event_attr!(onkeyup);
+        event_attr!(onload);
+        event_attr!(onloadeddata);
+        event_attr!(onloadedmetadata);
+        event_attr!(onloadstart);
+        event_attr!(onmousedown);
+        event_attr!(onmouseenter);
+        event_attr!(onmouseleave);
+        event_attr!(onmousemove);
+        event_attr!(onmouseout);
+        event_attr!(onmouseover);
+        event_attr!(onmouseup);
+        event_attr!(onpaste);
+        event_attr!(onpause);
+        event_attr!(onplay);
+        event_attr!(onplaying);
+        event_attr!(onprogress);
+        event_attr!(onratechange);
+        event_attr!(onreset);
+        event_attr!(onresize);
+        event_attr!(onscroll);
+        event_attr!(onscrollend);
+        event_attr!(onsecuritypolicyviolation);
+        event_attr!(onseeked);
+        event_attr!(onseeking);
+        event_attr!(onselect);
+        event_attr!(onslotchange);
+        event_attr!(onstalled);
+        event_attr!(onsubmit);
+        event_attr!(onsuspend);
+        event_attr!(ontimeupdate);
+        event_attr!(ontoggle);
+        event_attr!(onvolumechange);
+        event_attr!(onwaiting);
+        event_attr!(onwheel);
+    };
+}
+
 /// Global attributes
 macro_rules! global_attributes {
+    ( $elem:ident ) => {
+        #[doc = "Global attributes"]
+        impl<'t> $elem<'t> {
+            global_attributes!();
+        }
+
+        impl $crate::AriaAttrs for $elem<'_> {
+            fn aria_attr(&mut self, attr: &'static str, val: Value<'_>) {
+                self.tree.attr(self.depth, attr, val);
+            }
+        }
+    };
+
     () => {
+        try_attr_method!();
         global_attribute!(id);
         global_attribute!(class);
         // less-common...
@@ -222,13 +646,7 @@ macro_rules! global_attributes {
         global_attribute!(itemtype);
         global_attribute!(lang);
         global_attribute!(nonce);
-        global_attribute!(onblur);
-        global_attribute!(onchange);
-        global_attribute!(onclick);
-        global_attribute!(onclose);
-        global_attribute!(onerror);
-        global_attribute!(onfocus);
-        /* FIXME: more global event attributes? */
+        global_event_attributes!();
         global_attribute!(part);
         global_attribute!(popover);
         global_attribute!(role);
@@ -239,8 +657,20 @@ macro_rules! global_attributes {
         global_attribute!(title);
         global_attribute!(translate);
         /* FUTURE: virtualkeyboardpolicy, writingsuggestions */
+    };
+}
+
+/// ARIA attributes, for the `AriaAttrs` trait
+macro_rules! aria_attributes {
+    () => {
+        aria_attribute!(aria_activedescendant, "aria-activedescendant");
         aria_attribute!(aria_atomic, "aria-atomic");
+        aria_attribute!(aria_autocomplete, "aria-autocomplete");
         aria_attribute!(aria_busy, "aria-busy");
+        aria_attribute!(aria_checked, "aria-checked");
+        aria_attribute!(aria_colcount, "aria-colcount");
+        aria_attribute!(aria_colindex, "aria-colindex");
+        aria_attribute!(aria_colspan, "aria-colspan");
         aria_attribute!(aria_controls, "aria-controls");
         aria_attribute!(aria_current, "aria-current");
         aria_attribute!(aria_describedby, "aria-describedby");
@@ -248,17 +678,38 @@ macro_rules! global_attributes {
         aria_attribute!(aria_details, "aria-details");
         aria_attribute!(aria_disabled, "aria-disabled");
         aria_attribute!(aria_errormessage, "aria-errormessage");
+        aria_attribute!(aria_expanded, "aria-expanded", bool);
         aria_attribute!(aria_flowto, "aria-flowto");
         aria_attribute!(aria_haspopup, "aria-haspopup");
-        aria_attribute!(aria_hidden, "aria-hidden");
+        aria_attribute!(aria_hidden, "aria-hidden", bool);
         aria_attribute!(aria_invalid, "aria-invalid");
         aria_attribute!(aria_keyshortcuts, "aria-keyshortcuts");
         aria_attribute!(aria_label, "aria-label");
         aria_attribute!(aria_labelledby, "aria-labelledby");
+        aria_attribute!(aria_level, "aria-level");
         aria_attribute!(aria_live, "aria-live");
+        aria_attribute!(aria_modal, "aria-modal");
+        aria_attribute!(aria_multiline, "aria-multiline");
+        aria_attribute!(aria_multiselectable, "aria-multiselectable");
+        aria_attribute!(aria_orientation, "aria-orientation");
         aria_attribute!(aria_owns, "aria-owns");
+        aria_attribute!(aria_placeholder, "aria-placeholder");
+        aria_attribute!(aria_posinset, "aria-posinset");
+        aria_attribute!(aria_pressed, "aria-pressed");
+        aria_attribute!(aria_readonly, "aria-readonly");
         aria_attribute!(aria_relevant, "aria-relevant");
+        aria_attribute!(aria_required, "aria-required");
         aria_attribute!(aria_roledescription, "aria-roledescription");
+        aria_attribute!(aria_rowcount, "aria-rowcount");
+        aria_attribute!(aria_rowindex, "aria-rowindex");
+        aria_attribute!(aria_rowspan, "aria-rowspan");
+        aria_attribute!(aria_selected, "aria-selected");
+        aria_attribute!(aria_setsize, "aria-setsize");
+        aria_attribute!(aria_sort, "aria-sort");
+        aria_attribute!(aria_valuemax, "aria-valuemax");
+        aria_attribute!(aria_valuemin, "aria-valuemin");
+        aria_attribute!(aria_valuenow, "aria-valuenow");
+        aria_attribute!(aria_valuetext, "aria-valuetext");
     };
 }
 
@@ -266,8 +717,13 @@ macro_rules! global_attributes {
 macro_rules! elem_method {
     ( $meth:ident, $elem:ident ) => {
         #[doc = concat!("Add `", stringify!($elem), "` child element")]
+        #[doc = ""]
+        #[doc = "Any open child elements should be closed first."]
         #[allow(clippy::self_named_constructors)]
+        #[must_use]
         pub fn $meth(self: &mut Self) -> $elem<'_> {
+            #[cfg(feature = "validate")]
+            self.tree.check_parent(self.depth, $elem::TAG);
             let depth = self.tree.elem($elem::TAG, $elem::TP);
             $elem {
                 tree: self.tree,
@@ -277,6 +733,50 @@ macro_rules! elem_method {
     };
 }
 
+/// Create a custom element method
+macro_rules! custom_method {
+    () => {
+        /// Add custom element child (web component)
+        ///
+        /// - `tag`: Element tag, which must contain a hyphen and only
+        ///   lower-case ASCII letters, digits and hyphens
+        #[must_use]
+        pub fn custom(
+            &mut self,
+            tag: impl Into<Cow<'static, str>>,
+        ) -> Custom<'_> {
+            let tag = tag.into();
+            #[cfg(feature = "validate")]
+            self.tree.check_parent(self.depth, &tag);
+            let depth = self.tree.elem_custom(tag);
+            Custom {
+                tree: self.tree,
+                depth,
+            }
+        }
+
+        /// Add custom element child, checking its name
+        ///
+        /// Returns [Error::InvalidElementName] if `tag` is not a valid
+        /// custom element name.
+        ///
+        /// [Error::InvalidElementName]: crate::Error::InvalidElementName
+        pub fn try_custom(
+            &mut self,
+            tag: impl Into<Cow<'static, str>>,
+        ) -> Result<Custom<'_>, crate::Error> {
+            let tag = tag.into();
+            #[cfg(feature = "validate")]
+            self.tree.try_check_parent(self.depth, &tag)?;
+            let depth = self.tree.try_elem_custom(tag)?;
+            Ok(Custom {
+                tree: self.tree,
+                depth,
+            })
+        }
+    };
+}
+
 /// CData method
 macro_rules! cdata_methods {
     () => {
@@ -291,10 +791,26 @@ macro_rules! cdata_methods {
         where
             V: Into<Value<'a>>,
         {
+            #[cfg(feature = "validate")]
+            self.tree.check_parent(self.depth, "text");
             self.tree.cdata(text);
             self
         }
 
+        /// Add formatted character data content
+        ///
+        /// - `args`: Text arguments, from [format_args] (see [cdata_fmt])
+        ///
+        /// The text is escaped while it is formatted.
+        ///
+        /// [cdata_fmt]: crate::cdata_fmt
+        pub fn cdata_fmt(&mut self, args: std::fmt::Arguments) -> &mut Self {
+            #[cfg(feature = "validate")]
+            self.tree.check_parent(self.depth, "text");
+            self.tree.cdata_fmt(args);
+            self
+        }
+
         /// Add character data content with a maximum character limit
         ///
         /// - `&` ⇨ `&amp;`
@@ -304,9 +820,35 @@ macro_rules! cdata_methods {
         where
             V: Into<Value<'a>>,
         {
+            #[cfg(feature = "validate")]
+            self.tree.check_parent(self.depth, "text");
             self.tree.cdata_len(text, len);
             self
         }
+
+        /// Add character data content, truncated with a suffix
+        ///
+        /// If `text` is longer than `len`, it is truncated and `suffix`
+        /// (e.g. `"…"`) is appended.  With the `unicode-segmentation`
+        /// feature, truncation is on grapheme cluster boundaries.
+        ///
+        /// - `&` ⇨ `&amp;`
+        /// - `<` ⇨ `&lt;`
+        /// - `>` ⇨ `&gt;`
+        pub fn cdata_truncate<'a, V>(
+            &mut self,
+            text: V,
+            len: usize,
+            suffix: &str,
+        ) -> &mut Self
+        where
+            V: Into<Value<'a>>,
+        {
+            #[cfg(feature = "validate")]
+            self.tree.check_parent(self.depth, "text");
+            self.tree.cdata_truncate(text, len, suffix);
+            self
+        }
     };
 }
 
@@ -315,27 +857,83 @@ macro_rules! comment_raw_methods {
     () => {
         /// Add a comment
         ///
-        /// These characters will be replaced with entities:
+        /// Single hyphens are unchanged, but a space is inserted between
+        /// consecutive hyphens and after a trailing hyphen.  These
+        /// characters will be replaced with entities:
         ///
-        /// - `-` ⇨ `&hyphen;`
         /// - `<` ⇨ `&lt;`
         /// - `>` ⇨ `&gt;`
         pub fn comment<'v, V>(&mut self, com: V) -> &mut Self
         where
             V: Into<Value<'v>>,
         {
+            #[cfg(feature = "validate")]
+            self.tree.check_parent(self.depth, "comment");
             self.tree.comment(com);
             self
         }
 
+        /// Add a comment, replacing all hyphens
+        ///
+        /// - `-` ⇨ `&hyphen;`
+        /// - `<` ⇨ `&lt;`
+        /// - `>` ⇨ `&gt;`
+        pub fn comment_strict<'v, V>(&mut self, com: V) -> &mut Self
+        where
+            V: Into<Value<'v>>,
+        {
+            #[cfg(feature = "validate")]
+            self.tree.check_parent(self.depth, "comment");
+            self.tree.comment_strict(com);
+            self
+        }
+
         /// Add raw content
         ///
         /// **WARNING**: `trusted` is used verbatim, with no escaping;
         ///              do not call with untrusted content.
         pub fn raw(&mut self, trusted: impl AsRef<str>) -> &mut Self {
+            #[cfg(feature = "validate")]
+            self.tree.check_parent(self.depth, "raw content");
             self.tree.raw(trusted);
             self
         }
+
+        /// Add raw content, checking for broken markup
+        ///
+        /// Returns [Error::InvalidRaw] if the content has unbalanced `<` /
+        /// `>`, closes an element which is not open, or contains a
+        /// `<script` tag.
+        ///
+        /// [Error::InvalidRaw]: crate::Error::InvalidRaw
+        pub fn try_raw_checked(
+            &mut self,
+            trusted: impl AsRef<str>,
+        ) -> Result<&mut Self, crate::Error> {
+            #[cfg(feature = "validate")]
+            self.tree.try_check_parent(self.depth, "raw content")?;
+            self.tree.try_raw_checked(trusted)?;
+            Ok(self)
+        }
+
+        /// Add pre-sanitized HTML content
+        ///
+        /// `html` is used verbatim, but should be the output of an HTML
+        /// sanitizer.  Debug builds check that elements are closed.
+        pub fn fragment_str(&mut self, html: &str) -> &mut Self {
+            #[cfg(feature = "validate")]
+            self.tree.check_parent(self.depth, "fragment");
+            self.tree.fragment_str(html);
+            self
+        }
+
+        /// Append a fragment
+        pub fn append_fragment(&mut self, frag: &Fragment) -> &mut Self {
+            #[cfg(feature = "validate")]
+            self.tree.check_parent(self.depth, "fragment");
+            self.tree.append_fragment(frag);
+            self
+        }
     };
 }
 
@@ -373,6 +971,7 @@ macro_rules! flow_content {
         elem_method!(canvas, Canvas);
         elem_method!($cite, Cite);
         elem_method!(code, Code);
+        custom_method!();
         elem_method!(data, Data);
         elem_method!(datalist, DataList);
         elem_method!(del, Del);
@@ -383,6 +982,7 @@ macro_rules! flow_content {
         elem_method!(dl, Dl);
         elem_method!(em, Em);
         elem_method!(embed, Embed);
+        elem_method!(fencedframe, FencedFrame);
         elem_method!(fieldset, FieldSet);
         elem_method!(figure, Figure);
         elem_method!(footer, Footer);
@@ -462,12 +1062,14 @@ macro_rules! phrasing_content {
         elem_method!(canvas, Canvas);
         elem_method!($cite, Cite);
         elem_method!(code, Code);
+        custom_method!();
         elem_method!(data, Data);
         elem_method!(datalist, DataList);
         elem_method!(del, Del); // NOTE: containing only phrasing content
         elem_method!(dfn, Dfn);
         elem_method!(em, Em);
         elem_method!(embed, Embed);
+        elem_method!(fencedframe, FencedFrame);
         elem_method!(i, I);
         elem_method!(iframe, IFrame);
         elem_method!(img, Img);
@@ -532,6 +1134,7 @@ macro_rules! non_interactive_phrasing_content {
         elem_method!(dfn, Dfn);
         elem_method!(em, Em);
         // embed is interactive
+        // fencedframe is interactive
         elem_method!(i, I);
         // iframe is interactive
         elem_method!(img, Img); // with usemap attribute is interactive
@@ -601,6 +1204,7 @@ macro_rules! address_content {
         elem_method!(canvas, Canvas);
         elem_method!(cite, Cite);
         elem_method!(code, Code);
+        custom_method!();
         elem_method!(data, Data);
         elem_method!(datalist, DataList);
         elem_method!(del, Del);
@@ -611,6 +1215,7 @@ macro_rules! address_content {
         elem_method!(dl, Dl);
         elem_method!(em, Em);
         elem_method!(embed, Embed);
+        elem_method!(fencedframe, FencedFrame);
         elem_method!(fieldset, FieldSet);
         elem_method!(figure, Figure);
         // footer not allowed
@@ -711,6 +1316,29 @@ macro_rules! svg_elem {
                 self.tree.close_to(self.depth);
                 self.tree
             }
+
+            #[doc = "Close the element, checking that no child elements are open"]
+            #[doc = ""]
+            #[doc = concat!(
+                "Returns [Error::MismatchedClose](crate::Error::MismatchedClose)",
+                " if a child element is open, or ",
+                "[Error::UnbalancedClose](crate::Error::UnbalancedClose)",
+                " if the element was already closed.",
+            )]
+            pub fn try_end(
+                &'t mut self,
+            ) -> Result<&'t mut Tree, $crate::Error> {
+                self.tree.try_close_to(self.depth)?;
+                Ok(self.tree)
+            }
+
+            #[doc = "Reserve capacity for additional document text, in bytes"]
+            pub fn reserve(&mut self, additional: usize) -> &mut Self {
+                self.tree.reserve(additional);
+                self
+            }
+
+            cond_methods!();
         }
 
         #[doc = "Global SVG attributes"]
@@ -718,11 +1346,28 @@ macro_rules! svg_elem {
             svg_global_attributes!();
         }
 
+        #[cfg(feature = "codegen")]
+        impl $elem<'_> {
+            /// SVG element, for code generation
+            pub(crate) const CODEGEN_SVG: bool = true;
+
+            /// Get element methods, for code generation
+            pub(crate) fn codegen() -> $crate::codegen::ElemInfo {
+                let mut info = $crate::codegen::ElemInfo::new(
+                    Self::CODEGEN_SVG,
+                    $el,
+                    stringify!($elem),
+                );
+                codegen_table!(info, $items!($el); svg_global_attributes!(););
+                info
+            }
+        }
+
         impl<'t> Element<'t> for $elem<'t> {
             const TAG: &'static str = $el;
             const TP: ElemType = $tp;
-            fn new(tree: &'t mut Tree) -> Self {
-                $elem { tree, depth: 1 }
+            fn new(tree: &'t mut Tree, depth: usize) -> Self {
+                $elem { tree, depth }
             }
         }
     }
@@ -749,6 +1394,7 @@ macro_rules! svg_attr {
 /// SVG global attributes
 macro_rules! svg_global_attributes {
     () => {
+        try_attr_method!();
         svg_attr!(id);
         // NOTE: only allowed on *most* SVG elements, for some reason
         svg_attr!(class);
@@ -757,11 +1403,74 @@ macro_rules! svg_global_attributes {
         svg_attr!(autofocus, "autofocus", true);
         svg_attr!(lang);
         svg_attr!(tabindex);
-        svg_attr!(transform);
+        transform_methods!();
         data_attribute!();
     };
 }
 
+/// Pending `transform` attribute methods
+macro_rules! transform_methods {
+    () => {
+        /// Append to the pending [transform] attribute
+        ///
+        /// Pending transforms are added as one attribute when the start tag
+        /// ends, or with `apply_transform`.
+        ///
+        /// [transform]: https://developer.mozilla.org/en-US/docs/Web/SVG/Reference/Attribute/transform
+        pub fn transform<'a, V>(&mut self, val: V) -> &mut Self
+        where
+            V: Into<Value<'a>>,
+        {
+            let val = val.into();
+            if !val.is_none() {
+                self.tree.push_transform(self.depth, val.as_str());
+            }
+            self
+        }
+
+        /// Append `translate` to the pending `transform` attribute
+        pub fn svg_translate(&mut self, tx: f64, ty: f64) -> &mut Self {
+            let t = $crate::Transform::translate(tx, ty);
+            self.tree.push_transform(self.depth, t);
+            self
+        }
+
+        /// Append `rotate` to the pending `transform` attribute
+        pub fn svg_rotate(&mut self, deg: f64) -> &mut Self {
+            let t = $crate::Transform::rotate(deg);
+            self.tree.push_transform(self.depth, t);
+            self
+        }
+
+        /// Append `scale` to the pending `transform` attribute
+        pub fn svg_scale(&mut self, sx: f64, sy: f64) -> &mut Self {
+            let t = $crate::Transform::scale(sx, sy);
+            self.tree.push_transform(self.depth, t);
+            self
+        }
+
+        /// Append `skewX` to the pending `transform` attribute
+        pub fn svg_skew_x(&mut self, deg: f64) -> &mut Self {
+            let t = $crate::Transform::skew_x(deg);
+            self.tree.push_transform(self.depth, t);
+            self
+        }
+
+        /// Append `skewY` to the pending `transform` attribute
+        pub fn svg_skew_y(&mut self, deg: f64) -> &mut Self {
+            let t = $crate::Transform::skew_y(deg);
+            self.tree.push_transform(self.depth, t);
+            self
+        }
+
+        /// Add the pending `transform` attribute now
+        pub fn apply_transform(&mut self) -> &mut Self {
+            self.tree.apply_transform();
+            self
+        }
+    };
+}
+
 /// SVG support attributes
 macro_rules! svg_support_attr {
     () => {
@@ -1,6 +1,7 @@
 // poly.rs
 // Copyright (C) 2025-2026  Douglas P Lau
 //
+use crate::value::Value;
 use std::fmt;
 use std::fmt::Write;
 
@@ -12,7 +13,7 @@ use std::fmt::Write;
 /// points.precision(2);
 /// points.add([5, 5]);
 /// points.add((10.1, 20.2));
-/// println!("{points}");
+/// assert_eq!(points.to_string(), "5,5 10.1,20.2");
 /// ```
 ///
 /// [Polygon]: svg/struct.Polygon.html#method.points
@@ -39,6 +40,12 @@ impl From<PolyPointBuilder> for String {
     }
 }
 
+impl From<PolyPointBuilder> for Value<'_> {
+    fn from(poly: PolyPointBuilder) -> Self {
+        Value::from(poly.points)
+    }
+}
+
 impl PolyPointBuilder {
     /// Create a new SVG polygon / polyline points builder
     pub(crate) fn new() -> Self {
@@ -48,12 +55,71 @@ impl PolyPointBuilder {
         }
     }
 
+    /// Create a points builder from an iterator of points
+    ///
+    /// - `iter`: Points to add
+    /// - `precision`: Precision in decimal places
+    pub fn from_iter_with_precision<I, P, V>(iter: I, precision: usize) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<(V, V)>,
+        V: Into<f64> + Copy,
+    {
+        let iter = iter.into_iter();
+        let mut poly = PolyPointBuilder {
+            precision,
+            points: String::with_capacity(
+                iter.size_hint().0 * Self::point_len(precision),
+            ),
+        };
+        for p in iter {
+            poly.add(p);
+        }
+        poly
+    }
+
+    /// Create a points builder from a slice of points
+    ///
+    /// - `points`: Points to add
+    /// - `precision`: Precision in decimal places
+    ///
+    /// ```rust
+    /// # use hatmil::PolyPointBuilder;
+    /// let points = PolyPointBuilder::from_slice(&[(0, 0), (5, 10)], 2);
+    /// assert_eq!(points.to_string(), "0,0 5,10");
+    /// ```
+    pub fn from_slice<P, V>(points: &[P], precision: usize) -> Self
+    where
+        P: Into<(V, V)> + Copy,
+        V: Into<f64> + Copy,
+    {
+        Self::from_iter_with_precision(points.iter().copied(), precision)
+    }
+
+    /// Estimate the length of one point, for pre-allocation
+    fn point_len(precision: usize) -> usize {
+        // sign, 3 integer digits and decimal point, for x and y;
+        // plus comma and space separators
+        2 * (precision + 5) + 2
+    }
+
     /// Set the precision in decimal places
     pub fn precision(&mut self, digits: usize) -> &mut Self {
         self.precision = digits;
         self
     }
 
+    /// Check if no points have been added
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Remove all points, keeping allocated capacity for reuse
+    pub fn clear(&mut self) -> &mut Self {
+        self.points.clear();
+        self
+    }
+
     /// Write one value
     fn value(&mut self, v: f64) {
         write!(&mut self.points, "{v:.0$}", self.precision).unwrap();
@@ -96,47 +162,78 @@ mod test {
 
     #[test]
     fn empty() {
-        let poly = PolyPointBuilder::new();
+        let poly = PolyPointBuilder::from_slice::<[i32; 2], i32>(&[], 2);
         assert_eq!(poly.to_string(), "");
+        assert!(poly.is_empty());
     }
 
     #[test]
     fn mv() {
-        let mut poly = PolyPointBuilder::new();
-        poly.add([1, 2]);
+        let poly = PolyPointBuilder::from_slice(&[[1, 2]], 2);
         assert_eq!(poly.to_string(), "1,2");
     }
 
     #[test]
     fn line() {
-        let mut poly = PolyPointBuilder::new();
-        poly.add([1, 2]);
-        poly.add([2, 1]);
+        let poly = PolyPointBuilder::from_slice(&[[1, 2], [2, 1]], 2);
         assert_eq!(poly.to_string(), "1,2 2,1");
     }
 
     #[test]
-    fn rounding() {
+    fn clear() {
         let mut poly = PolyPointBuilder::new();
-        poly.add([2.0001, 0.003]);
+        poly.precision(3);
+        poly.add([1.5, 2.25]).add([3.0, 4.0]);
+        let capacity = poly.points.capacity();
+        poly.clear();
+        assert!(poly.is_empty());
+        assert_eq!(poly.points.capacity(), capacity);
+        poly.add([5, 6]).add([7.125, 8.0]);
+        let mut fresh = PolyPointBuilder::new();
+        fresh.precision(3);
+        fresh.add([5, 6]).add([7.125, 8.0]);
+        assert_eq!(poly.to_string(), fresh.to_string());
+    }
+
+    #[test]
+    fn from_slice_capacity() {
+        let points = [(-100.25, 999.125); 8];
+        let poly = PolyPointBuilder::from_slice(&points, 3);
+        // no growth beyond the pre-allocated capacity
+        assert_eq!(
+            poly.points.capacity(),
+            points.len() * PolyPointBuilder::point_len(3)
+        );
+    }
+
+    #[test]
+    fn from_iter_capacity() {
+        let points = (0..10).map(|i| (i * 100, -i * 100));
+        let poly = PolyPointBuilder::from_iter_with_precision(points, 2);
+        // no growth beyond the pre-allocated capacity
+        assert_eq!(poly.points.capacity(), 10 * PolyPointBuilder::point_len(2));
+    }
+
+    #[test]
+    fn rounding() {
+        let poly = PolyPointBuilder::from_slice(&[[2.0001, 0.003]], 2);
         assert_eq!(poly.to_string(), "2,0");
     }
 
     #[test]
     fn two_decimal_places() {
-        let mut poly = PolyPointBuilder::new();
-        poly.add([2.2222, 9.994]);
-        poly.add([4.444444, 8.88888]);
+        let poly = PolyPointBuilder::from_slice(
+            &[(2.2222, 9.994), (4.444444, 8.88888)],
+            2,
+        );
         assert_eq!(poly.to_string(), "2.22,9.99 4.44,8.89");
     }
 
     #[test]
     fn three_decimal_places() {
-        let mut poly = PolyPointBuilder::new();
-        poly.precision(3);
-        poly.add([2.2222, 9.994]);
-        poly.add([4.444444, 8.88888]);
-        poly.add([5.444444, 8.88888]);
+        let points =
+            [[2.2222, 9.994], [4.444444, 8.88888], [5.444444, 8.88888]];
+        let poly = PolyPointBuilder::from_iter_with_precision(points, 3);
         assert_eq!(poly.to_string(), "2.222,9.994 4.444,8.889 5.444,8.889");
     }
 }
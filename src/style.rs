@@ -0,0 +1,448 @@
+// style.rs
+//
+// Copyright (C) 2026  Douglas P Lau
+//
+use crate::value::Value;
+use std::fmt;
+
+/// Inline CSS [style] attribute builder
+///
+/// ```rust
+/// use hatmil::{StyleAttr, Tree, html::Div};
+///
+/// let mut style = StyleAttr::new();
+/// style.set("color", "red").set("font-weight", "bold");
+/// let mut tree = Tree::new();
+/// tree.root::<Div>().style(style);
+/// assert_eq!(
+///     String::from(tree),
+///     "<div style=\"color:red;font-weight:bold\"></div>",
+/// );
+/// ```
+///
+/// [style]: https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Global_attributes/style
+#[derive(Clone, Debug, Default)]
+pub struct StyleAttr {
+    /// Declarations string
+    style: String,
+}
+
+impl fmt::Display for StyleAttr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.style)
+    }
+}
+
+impl From<StyleAttr> for String {
+    fn from(style: StyleAttr) -> Self {
+        // zero-copy alternative to fmt::Display
+        style.style
+    }
+}
+
+impl From<StyleAttr> for Value<'_> {
+    fn from(style: StyleAttr) -> Self {
+        Value::from(style.style)
+    }
+}
+
+impl<'a> From<&'a StyleAttr> for Value<'a> {
+    fn from(style: &'a StyleAttr) -> Self {
+        Value::from(style.style.as_str())
+    }
+}
+
+impl StyleAttr {
+    /// Create a new style attribute builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a property
+    ///
+    /// - `key`: Property name
+    /// - `val`: Property value
+    ///
+    /// Panics if `key` is not a valid property name, or `val` contains
+    /// `;`, `{` or `}` outside of a quoted string.
+    pub fn set(&mut self, key: &str, val: &str) -> &mut Self {
+        check_name(key);
+        check_value(val);
+        if !self.style.is_empty() {
+            self.style.push(';');
+        }
+        self.style.push_str(key);
+        self.style.push(':');
+        self.style.push_str(val);
+        self
+    }
+
+    /// Check if no properties have been set
+    pub fn is_empty(&self) -> bool {
+        self.style.is_empty()
+    }
+}
+
+/// CSS style sheet builder
+///
+/// ```rust
+/// use hatmil::{Css, Tree, html::Head};
+///
+/// let mut css = Css::new();
+/// css.rule("p").prop("color", "red").prop("margin", "0");
+/// css.rule("a:hover").prop("color", "blue");
+/// let mut tree = Tree::new();
+/// tree.root::<Head>().style_el().style_text(&css);
+/// assert_eq!(
+///     String::from(tree),
+///     "<head><style>p{color:red;margin:0}a:hover{color:blue}</style></head>",
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Css {
+    /// Style sheet string
+    css: String,
+    /// Kind of content, once anything is added
+    kind: Option<CssKind>,
+}
+
+/// Kind of CSS content
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CssKind {
+    /// Rules, for a `style` element
+    Rules,
+    /// Declarations only, for a `style` attribute
+    Props,
+}
+
+/// CSS rule scope, borrowed from a [Css] builder
+pub struct CssRule<'c> {
+    /// Style sheet string
+    css: &'c mut String,
+    /// Declarations are within a `{}` block
+    block: bool,
+    /// No declarations added yet
+    empty: bool,
+}
+
+impl fmt::Display for Css {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.css)
+    }
+}
+
+impl AsRef<str> for Css {
+    fn as_ref(&self) -> &str {
+        &self.css
+    }
+}
+
+impl From<Css> for String {
+    fn from(css: Css) -> Self {
+        css.css
+    }
+}
+
+impl From<Css> for Value<'_> {
+    fn from(css: Css) -> Self {
+        Value::from(css.css)
+    }
+}
+
+impl<'a> From<&'a Css> for Value<'a> {
+    fn from(css: &'a Css) -> Self {
+        Value::from(css.css.as_str())
+    }
+}
+
+/// Panic if CSS text could end a `style` element
+fn check_css(text: &str) {
+    if text
+        .as_bytes()
+        .windows(7)
+        .any(|w| w.eq_ignore_ascii_case(b"</style"))
+    {
+        panic!("invalid CSS: {text:?}");
+    }
+}
+
+/// Check if text has `;`, `{` or `}` outside of a quoted string
+fn has_unquoted_delimiter(text: &str) -> bool {
+    let mut quote = None;
+    let mut escape = false;
+    for c in text.chars() {
+        match (quote, c) {
+            _ if escape => escape = false,
+            (_, '\\') => escape = true,
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if c == q => quote = None,
+            (None, ';' | '{' | '}') => return true,
+            _ => (),
+        }
+    }
+    false
+}
+
+/// Panic if a property value could end its declaration or block
+///
+/// `;`, `{` and `}` are only allowed within quoted strings.
+fn check_value(value: &str) {
+    check_css(value);
+    if has_unquoted_delimiter(value) {
+        panic!("invalid CSS value: {value:?}");
+    }
+}
+
+/// Panic if a selector could end its rule or start another
+///
+/// `;`, `{` and `}` are only allowed within quoted strings.
+fn check_selector(selector: &str) {
+    check_css(selector);
+    if has_unquoted_delimiter(selector) {
+        panic!("invalid CSS selector: {selector:?}");
+    }
+}
+
+/// Panic if a property name is not valid
+fn check_name(name: &str) {
+    if name.is_empty()
+        || name.chars().any(|c| {
+            c.is_whitespace() || matches!(c, ':' | ';' | '{' | '}' | '<')
+        })
+    {
+        panic!("invalid CSS property: {name:?}");
+    }
+}
+
+impl Css {
+    /// Create a new style sheet builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the kind of content, panicking if it is mixed
+    fn set_kind(&mut self, kind: CssKind) {
+        match self.kind {
+            Some(k) if k != kind => {
+                panic!("cannot mix CSS rules and style_props")
+            }
+            _ => self.kind = Some(kind),
+        }
+    }
+
+    /// Add a rule
+    ///
+    /// Panics if `selector` contains `</style` or `;`, `{` or `}` outside
+    /// of a quoted string, or if [style_props] was used.
+    ///
+    /// [style_props]: Css::style_props
+    pub fn rule(&mut self, selector: &str) -> CssRule<'_> {
+        check_selector(selector);
+        self.set_kind(CssKind::Rules);
+        self.css.push_str(selector);
+        self.css.push_str("{}");
+        CssRule {
+            css: &mut self.css,
+            block: true,
+            empty: true,
+        }
+    }
+
+    /// Add declarations only, for a `style` attribute
+    ///
+    /// Panics if [rule] was used.
+    ///
+    /// [rule]: Css::rule
+    ///
+    /// ```rust
+    /// use hatmil::{Css, Tree, html::Div};
+    ///
+    /// let mut css = Css::new();
+    /// css.style_props().prop("color", "red").prop("font-weight", "bold");
+    /// let mut tree = Tree::new();
+    /// tree.root::<Div>().style(css);
+    /// assert_eq!(
+    ///     String::from(tree),
+    ///     "<div style=\"color:red;font-weight:bold\"></div>",
+    /// );
+    /// ```
+    pub fn style_props(&mut self) -> CssRule<'_> {
+        self.set_kind(CssKind::Props);
+        let empty = self.css.is_empty();
+        CssRule {
+            css: &mut self.css,
+            block: false,
+            empty,
+        }
+    }
+
+    /// Check if nothing has been added
+    pub fn is_empty(&self) -> bool {
+        self.css.is_empty()
+    }
+}
+
+impl CssRule<'_> {
+    /// Add a property declaration
+    ///
+    /// - `name`: Property name
+    /// - `value`: Property value
+    ///
+    /// Panics if `name` is not a valid property name, or `value` contains
+    /// `</style` or `;`, `{` or `}` outside of a quoted string.
+    pub fn prop(&mut self, name: &str, value: &str) -> &mut Self {
+        check_name(name);
+        check_value(value);
+        if self.block {
+            self.css.pop();
+        }
+        if !self.empty {
+            self.css.push(';');
+        }
+        self.css.push_str(name);
+        self.css.push(':');
+        self.css.push_str(value);
+        if self.block {
+            self.css.push('}');
+        }
+        self.empty = false;
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Tree;
+    use crate::html::{Div, Head, P};
+
+    #[test]
+    fn style() {
+        let mut style = StyleAttr::new();
+        assert!(style.is_empty());
+        style.set("color", "red");
+        style.set("font-weight", "bold");
+        assert_eq!(style.to_string(), "color:red;font-weight:bold");
+        let mut tree = Tree::new();
+        tree.root::<P>().style(&style).cdata("Bold");
+        assert_eq!(
+            String::from(tree),
+            "<p style=\"color:red;font-weight:bold\">Bold</p>"
+        );
+        assert_eq!(String::from(style), "color:red;font-weight:bold");
+    }
+
+    #[test]
+    fn escaping() {
+        let mut style = StyleAttr::new();
+        style.set("font-family", "\"Fira Sans\"");
+        let mut tree = Tree::new();
+        tree.root::<P>().style(style);
+        assert_eq!(
+            String::from(tree),
+            "<p style=\"font-family:&quot;Fira Sans&quot;\"></p>"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid CSS value")]
+    fn style_value() {
+        StyleAttr::new().set("color", "red;position:fixed");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid CSS property")]
+    fn style_name() {
+        StyleAttr::new().set("color:red;position", "fixed");
+    }
+
+    #[test]
+    fn style_sheet() {
+        let mut css = Css::new();
+        assert!(css.is_empty());
+        css.rule("body > p")
+            .prop("color", "#333")
+            .prop("font-family", "\"Fira Sans\", sans-serif");
+        css.rule("a[href^=\"http\"]::after")
+            .prop("content", "'\\2197'");
+        css.rule(".empty");
+        let text = "body > p{color:#333;font-family:\"Fira Sans\", sans-serif}\
+            a[href^=\"http\"]::after{content:'\\2197'}.empty{}";
+        assert_eq!(css.as_ref(), text);
+        let mut tree = Tree::new();
+        tree.root::<Head>().style_el().style_text(css);
+        assert_eq!(
+            String::from(tree),
+            format!("<head><style>{text}</style></head>")
+        );
+    }
+
+    #[test]
+    fn style_props() {
+        let mut css = Css::new();
+        css.style_props().prop("color", "red");
+        css.style_props().prop("background", "url(\"a&b.png\")");
+        let mut tree = Tree::new();
+        tree.root::<Div>().style(&css);
+        assert_eq!(
+            String::from(tree),
+            "<div style=\"color:red;background:url(&quot;a&amp;b.png&quot;)\"></div>"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid CSS")]
+    fn style_end() {
+        Css::new().rule("p").prop("content", "'</STYLE>'");
+    }
+
+    #[test]
+    fn quoted_values() {
+        let mut css = Css::new();
+        css.rule("p::before").prop("content", "\"{;}\"");
+        css.rule("q::after").prop("content", "'\\';'");
+        assert_eq!(
+            css.as_ref(),
+            "p::before{content:\"{;}\"}q::after{content:'\\';'}"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid CSS value")]
+    fn value_semicolon() {
+        Css::new().style_props().prop("color", "red;position:fixed");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid CSS value")]
+    fn value_brace() {
+        Css::new().rule("p").prop("color", "red}body{color:blue");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid CSS selector")]
+    fn selector_brace() {
+        Css::new().rule("p{color:red}body").prop("color", "blue");
+    }
+
+    #[test]
+    fn quoted_selector() {
+        let mut css = Css::new();
+        css.rule("a[title=\"{;}\"]").prop("color", "red");
+        assert_eq!(css.as_ref(), "a[title=\"{;}\"]{color:red}");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid CSS property")]
+    fn prop_name() {
+        Css::new().rule("p").prop("color:red;x", "blue");
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot mix CSS rules and style_props")]
+    fn mixed() {
+        let mut css = Css::new();
+        css.rule("p").prop("color", "red");
+        css.style_props().prop("margin", "0");
+    }
+}
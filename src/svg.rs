@@ -3,7 +3,31 @@
 // Copyright (C) 2025-2026  Douglas P Lau
 //
 //! SVG Elements -- _Scalable Vector Graphics_
+//!
+//! SVG elements with no content are self-closing:
+//!
+//! ```rust
+//! use hatmil::{Tree, svg::{Path, Polygon, Svg}};
+//!
+//! let mut d = Path::def_builder();
+//! d.move_to([0, 0]).line([10, 10]).close();
+//! let mut points = Polygon::point_builder();
+//! points.add([0, 0]).add([5, 10]).add([10, 0]);
+//! let mut tree = Tree::new();
+//! let mut svg = tree.root::<Svg>();
+//! svg.view_box("0 0 10 10");
+//! svg.path().d(d).close();
+//! svg.polygon().points(points).fill("red").close();
+//! svg.text().x(5).y(5).cdata("A < B");
+//! assert_eq!(
+//!     String::from(tree),
+//!     "<svg viewBox=\"0 0 10 10\"><path d=\"m0 0l10 10z\" />\
+//!     <polygon points=\"0,0 5,10 10,0\" fill=\"red\" />\
+//!     <text x=\"5\" y=\"5\">A &lt; B</text></svg>",
+//! );
+//! ```
 use crate::definition::PathDefBuilder;
+use crate::fragment::Fragment;
 use crate::html::Link;
 use crate::poly::PolyPointBuilder;
 use crate::tree::{ElemType, Element, Tree};
@@ -14,6 +38,7 @@ macro_rules! a_items {
     ( $el:literal ) => {
         html_attr!($el, download);
         html_attr!($el, href);
+        url_attr!(href_url, "href");
         html_attr!($el, hreflang);
         /* interestfor */
         html_attr!($el, ping);
@@ -373,6 +398,9 @@ svg_elem!(
 macro_rules! fe_flood_items {
     ( $el:literal ) => {
         filter_attr!();
+        // presentation attributes
+        svg_attr!(flood_color, "flood-color");
+        svg_attr!(flood_opacity, "flood-opacity");
         elem_method!(animate, Animate);
         elem_method!(set, Set);
         comment_raw_methods!();
@@ -992,6 +1020,9 @@ svg_elem!("set", Set, "Set Value", set_items());
 macro_rules! stop_items {
     ( $el:literal ) => {
         svg_attr!(offset);
+        // presentation attributes
+        svg_attr!(stop_color, "stop-color");
+        svg_attr!(stop_opacity, "stop-opacity");
         elem_method!(animate, Animate);
         elem_method!(set, Set);
         elem_method!(script, Script);
@@ -1158,6 +1189,22 @@ macro_rules! use_items {
 }
 svg_elem!("use", Use, "Use", use_items());
 
+impl<'t> Use<'t> {
+    /// Make new "root" `<use>` element referencing an element `id`
+    pub fn new_ref(tree: &'t mut Tree, id: &str) -> Self {
+        let mut r#use = tree.root::<Use>();
+        r#use.xref(id);
+        r#use
+    }
+
+    /// Add `href` attribute referencing an element `id`
+    ///
+    /// A `#` is prepended to `id`.
+    pub fn xref(&mut self, id: &str) -> &mut Self {
+        self.attr_fmt("href", format_args!("#{id}"))
+    }
+}
+
 // View element
 macro_rules! view_items {
     ( $el:literal ) => {
@@ -1184,8 +1231,6 @@ svg_elem!("view", View, "View", view_items());
 // - "fill-opacity" fill_opacity
 // - "fill-rule" fill_rule
 // - "filter" filter
-// - "flood-color" flood_color
-// - "flood-opacity" flood_opacity
 // - "font-family" font_family
 // - "font-size" font_size
 // - "font-size-adjust" font_size_adjust
@@ -1205,8 +1250,6 @@ svg_elem!("view", View, "View", view_items());
 // - "paint-order" paint_order
 // - "pointer-events" pointer_events
 // - "shape-rendering" shape_rendering
-// - "stop-color" stop_color
-// - "stop-opacity" stop_opacity
 // - "stroke-dasharray" stroke_dasharray
 // - "stroke-dashoffset" stroke_dashoffset
 // - "stroke-linecap" stroke_linecap
@@ -1247,6 +1290,157 @@ mod test {
         );
     }
 
+    #[test]
+    fn fe_merge() {
+        let mut tree = Tree::new();
+        let mut merge = tree.root::<FeMerge>();
+        merge.fe_merge_node().r#in("SourceGraphic").close();
+        merge.fe_merge_node().r#in("flood").close();
+        assert_eq!(
+            tree.to_string(),
+            "<feMerge><feMergeNode in=\"SourceGraphic\" />\
+            <feMergeNode in=\"flood\" /></feMerge>"
+        );
+    }
+
+    #[test]
+    fn fe_component_transfer() {
+        let mut tree = Tree::new();
+        let mut transfer = tree.root::<FeComponentTransfer>();
+        transfer.fe_func_r().r#type("linear").slope(0.5);
+        assert_eq!(
+            tree.to_string(),
+            "<feComponentTransfer>\
+            <feFuncR type=\"linear\" slope=\"0.5\" />\
+            </feComponentTransfer>"
+        );
+    }
+
+    #[test]
+    fn fe_lighting() {
+        let mut tree = Tree::new();
+        let mut filter = tree.root::<Filter>();
+        filter
+            .fe_diffuse_lighting()
+            .fe_point_light()
+            .x(100)
+            .y(100)
+            .z(200)
+            .close()
+            .close();
+        filter
+            .fe_specular_lighting()
+            .fe_spot_light()
+            .x(10)
+            .points_at_x(50)
+            .limiting_cone_angle(30);
+        assert_eq!(
+            tree.to_string(),
+            "<filter><feDiffuseLighting>\
+            <fePointLight x=\"100\" y=\"100\" z=\"200\" />\
+            </feDiffuseLighting><feSpecularLighting>\
+            <feSpotLight x=\"10\" pointsAtX=\"50\" limitingConeAngle=\"30\" />\
+            </feSpecularLighting></filter>"
+        );
+    }
+
+    #[test]
+    fn text() {
+        let mut tree = Tree::new();
+        let mut svg = tree.root::<Svg>();
+        svg.text().x(10).y(20).cdata("Hello, world!").close();
+        let mut text = svg.text();
+        text.cdata("a & b ");
+        text.tspan().cdata("<c>").close();
+        text.text_path().href("#p").cdata("d > e").comment("f");
+        assert_eq!(
+            tree.to_string(),
+            "<svg><text x=\"10\" y=\"20\">Hello, world!</text>\
+            <text>a &amp; b <tspan>&lt;c&gt;</tspan>\
+            <textPath href=\"#p\">d &gt; e<!--f--></textPath></text></svg>"
+        );
+    }
+
+    #[test]
+    fn descriptive() {
+        let mut tree = Tree::new();
+        let mut svg = tree.root::<Svg>();
+        svg.title().cdata("My SVG").close();
+        svg.desc().cdata("A blue circle").close();
+        svg.metadata().cdata("<x> & y");
+        assert_eq!(
+            tree.to_string(),
+            "<svg><title>My SVG</title><desc>A blue circle</desc>\
+            <metadata>&lt;x&gt; &amp; y</metadata></svg>"
+        );
+    }
+
+    #[test]
+    fn gradient() {
+        let mut tree = Tree::new();
+        let mut svg = tree.root::<Svg>();
+        let mut grad = svg.linear_gradient();
+        grad.stop().offset(0).stop_color("red").close();
+        grad.stop().offset(1).stop_color("blue").close();
+        grad.close();
+        svg.radial_gradient().stop().offset("50%").stop_opacity(0.5);
+        assert_eq!(
+            tree.to_string(),
+            "<svg><linearGradient>\
+            <stop offset=\"0\" stop-color=\"red\" />\
+            <stop offset=\"1\" stop-color=\"blue\" />\
+            </linearGradient><radialGradient>\
+            <stop offset=\"50%\" stop-opacity=\"0.5\" />\
+            </radialGradient></svg>"
+        );
+    }
+
+    #[test]
+    fn animate_motion() {
+        let mut tree = Tree::new();
+        tree.root::<AnimateMotion>()
+            .dur("5s")
+            .mpath()
+            .href("#myPath");
+        assert_eq!(
+            tree.to_string(),
+            "<animateMotion dur=\"5s\"><mpath href=\"#myPath\" /></animateMotion>"
+        );
+    }
+
+    #[test]
+    fn use_xref() {
+        let mut tree = Tree::new();
+        let mut svg = tree.root::<Svg>();
+        svg.r#use().xref("mySymbol").x(10).y(10);
+        assert_eq!(
+            tree.to_string(),
+            "<svg><use href=\"#mySymbol\" x=\"10\" y=\"10\" /></svg>"
+        );
+        let mut tree = Tree::new();
+        Use::new_ref(&mut tree, "dot").width(5);
+        assert_eq!(tree.to_string(), "<use href=\"#dot\" width=\"5\" />");
+    }
+
+    #[test]
+    fn standalone() {
+        let mut tree = Tree::new();
+        tree.xml_declaration();
+        let mut svg = tree.root::<Svg>();
+        svg.xmlns("http://www.w3.org/2000/svg")
+            .width(100)
+            .height(100)
+            .view_box("0 0 100 100");
+        svg.circle().cx(50).cy(50).r(40).fill("red");
+        assert_eq!(
+            String::from(tree),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+            <svg xmlns=\"http://www.w3.org/2000/svg\" width=\"100\" height=\"100\" viewBox=\"0 0 100 100\">\
+            <circle cx=\"50\" cy=\"50\" r=\"40\" fill=\"red\" />\
+            </svg>"
+        );
+    }
+
     #[test]
     fn path() {
         let mut tree = Tree::new();
@@ -0,0 +1,389 @@
+// transform.rs
+//
+// Copyright (C) 2026  Douglas P Lau
+//
+use crate::value::{SvgNum, Value};
+use std::fmt;
+
+/// Transform function
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Func {
+    /// Translate by x, y
+    Translate(f64, f64),
+    /// Rotate (degrees)
+    Rotate(f64),
+    /// Scale by x, y
+    Scale(f64, f64),
+    /// Skew along X axis (degrees)
+    SkewX(f64),
+    /// Skew along Y axis (degrees)
+    SkewY(f64),
+    /// Transform matrix
+    Matrix([f64; 6]),
+}
+
+/// SVG [transform] function
+///
+/// Numbers are written with up to 4 decimal places, trimming trailing zeros.
+///
+/// ```rust
+/// use hatmil::{Transform, Tree, svg::G};
+///
+/// let mut tree = Tree::new();
+/// tree.root::<G>()
+///     .transform(Transform::translate(10.0, 20.0).then(Transform::rotate(45.0)));
+/// assert_eq!(
+///     String::from(tree),
+///     "<g transform=\"translate(10 20) rotate(45)\" />",
+/// );
+/// ```
+///
+/// [transform]: https://developer.mozilla.org/en-US/docs/Web/SVG/Reference/Attribute/transform
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+    /// Transform function
+    func: Func,
+}
+
+/// List of SVG [Transform] functions, applied in order
+///
+/// To compose a `transform` attribute from chained element methods, see
+/// [SvgTransformList].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TransformList {
+    /// Transform functions
+    transforms: Vec<Transform>,
+}
+
+/// Pending SVG `transform` attribute of an element
+///
+/// Element methods such as `transform`, `svg_translate` and `svg_rotate`
+/// append to this list, which is added as one attribute when the start tag
+/// ends, or with `apply_transform`.
+///
+/// ```rust
+/// use hatmil::{Tree, svg::G};
+///
+/// let mut tree = Tree::new();
+/// tree.root::<G>()
+///     .transform("scale(2)")
+///     .svg_translate(10.0, 20.0)
+///     .svg_rotate(45.0);
+/// assert_eq!(
+///     String::from(tree),
+///     "<g transform=\"scale(2) translate(10 20) rotate(45)\" />",
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SvgTransformList {
+    /// Transform functions, separated by spaces
+    list: String,
+}
+
+impl fmt::Display for Transform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.func {
+            Func::Translate(tx, ty) => {
+                write!(f, "translate({} {})", SvgNum(tx), SvgNum(ty))
+            }
+            Func::Rotate(deg) => write!(f, "rotate({})", SvgNum(deg)),
+            Func::Scale(sx, sy) => {
+                write!(f, "scale({} {})", SvgNum(sx), SvgNum(sy))
+            }
+            Func::SkewX(deg) => write!(f, "skewX({})", SvgNum(deg)),
+            Func::SkewY(deg) => write!(f, "skewY({})", SvgNum(deg)),
+            Func::Matrix(m) => {
+                write!(f, "matrix(")?;
+                for (i, v) in m.into_iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{}", SvgNum(v))?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+impl From<Transform> for Value<'_> {
+    fn from(t: Transform) -> Self {
+        Value::from(t.to_string())
+    }
+}
+
+impl Transform {
+    /// Translate by `tx`, `ty`
+    pub fn translate(tx: f64, ty: f64) -> Self {
+        Transform {
+            func: Func::Translate(tx, ty),
+        }
+    }
+
+    /// Rotate by `deg` degrees
+    pub fn rotate(deg: f64) -> Self {
+        Transform {
+            func: Func::Rotate(deg),
+        }
+    }
+
+    /// Scale by `sx`, `sy`
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        Transform {
+            func: Func::Scale(sx, sy),
+        }
+    }
+
+    /// Skew along the X axis by `deg` degrees
+    pub fn skew_x(deg: f64) -> Self {
+        Transform {
+            func: Func::SkewX(deg),
+        }
+    }
+
+    /// Skew along the Y axis by `deg` degrees
+    pub fn skew_y(deg: f64) -> Self {
+        Transform {
+            func: Func::SkewY(deg),
+        }
+    }
+
+    /// Transform by matrix `[a c e] [b d f] [0 0 1]`
+    pub fn matrix(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> Self {
+        Transform {
+            func: Func::Matrix([a, b, c, d, e, f]),
+        }
+    }
+
+    /// Chain another transform, making a list
+    pub fn then(self, next: Transform) -> TransformList {
+        TransformList::from(self).then(next)
+    }
+}
+
+impl fmt::Display for TransformList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, t) in self.transforms.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{t}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Transform> for TransformList {
+    fn from(t: Transform) -> Self {
+        TransformList {
+            transforms: vec![t],
+        }
+    }
+}
+
+impl From<TransformList> for Value<'_> {
+    fn from(list: TransformList) -> Self {
+        Value::from(list.to_string())
+    }
+}
+
+impl TransformList {
+    /// Create an empty transform list
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Chain another transform
+    pub fn then(mut self, next: Transform) -> Self {
+        self.push(next);
+        self
+    }
+
+    /// Add a transform to the end of the list
+    pub fn push(&mut self, t: Transform) -> &mut Self {
+        self.transforms.push(t);
+        self
+    }
+
+    /// Check if the list is empty
+    pub fn is_empty(&self) -> bool {
+        self.transforms.is_empty()
+    }
+}
+
+impl fmt::Display for SvgTransformList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.list)
+    }
+}
+
+impl From<SvgTransformList> for Value<'_> {
+    fn from(list: SvgTransformList) -> Self {
+        Value::from(list.list)
+    }
+}
+
+impl SvgTransformList {
+    /// Create an empty transform list
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append transform functions to the end of the list
+    pub fn push(&mut self, t: impl fmt::Display) -> &mut Self {
+        use std::fmt::Write;
+        let start = self.list.len();
+        if start > 0 {
+            self.list.push(' ');
+        }
+        let pos = self.list.len();
+        write!(self.list, "{t}")
+            .expect("Display implementation returned an error");
+        if self.list.len() == pos {
+            self.list.truncate(start);
+        }
+        self
+    }
+
+    /// Check if the list is empty
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Tree;
+    use crate::svg::{G, Svg};
+
+    #[test]
+    fn transforms() {
+        assert_eq!(
+            Transform::translate(10.0, 20.0).to_string(),
+            "translate(10 20)"
+        );
+        assert_eq!(Transform::rotate(-45.5).to_string(), "rotate(-45.5)");
+        assert_eq!(Transform::scale(2.0, 0.5).to_string(), "scale(2 0.5)");
+        assert_eq!(Transform::skew_x(30.0).to_string(), "skewX(30)");
+        assert_eq!(Transform::skew_y(15.0).to_string(), "skewY(15)");
+        assert_eq!(
+            Transform::matrix(1.0, 0.0, 0.0, 1.0, 5.0, 6.0).to_string(),
+            "matrix(1 0 0 1 5 6)"
+        );
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(
+            Transform::translate(0.1 + 0.2, -0.00001).to_string(),
+            "translate(0.3 0)"
+        );
+        assert_eq!(
+            Transform::rotate(100.0 / 3.0).to_string(),
+            "rotate(33.3333)"
+        );
+        let (sin, cos) = 45_f64.to_radians().sin_cos();
+        assert_eq!(
+            Transform::matrix(cos, sin, -sin, cos, 0.0, -0.0).to_string(),
+            "matrix(0.7071 0.7071 -0.7071 0.7071 0 0)"
+        );
+    }
+
+    #[test]
+    fn list() {
+        let list =
+            Transform::translate(10.0, 20.0).then(Transform::rotate(45.0));
+        assert_eq!(list.to_string(), "translate(10 20) rotate(45)");
+        let mut list = TransformList::new();
+        assert!(list.is_empty());
+        list.push(Transform::scale(2.0, 2.0))
+            .push(Transform::skew_x(10.0));
+        assert_eq!(list.to_string(), "scale(2 2) skewX(10)");
+    }
+
+    #[test]
+    fn svg() {
+        let mut tree = Tree::new();
+        let mut svg = tree.root::<Svg>();
+        svg.rect()
+            .width(10)
+            .height(10)
+            .transform(
+                Transform::translate(10.0, 20.0).then(Transform::rotate(45.0)),
+            )
+            .close();
+        svg.rect().transform(Transform::scale(2.0, 3.0));
+        assert_eq!(
+            String::from(tree),
+            "<svg><rect width=\"10\" height=\"10\" \
+            transform=\"translate(10 20) rotate(45)\" />\
+            <rect transform=\"scale(2 3)\" /></svg>"
+        );
+    }
+
+    #[test]
+    fn pending() {
+        let mut tree = Tree::new();
+        let mut svg = tree.root::<Svg>();
+        svg.g().svg_translate(10.0, 20.0).svg_rotate(45.0).close();
+        let mut g = svg.g();
+        g.svg_scale(2.0, 2.0).id("a").svg_skew_x(5.0);
+        g.rect().svg_skew_y(1.5);
+        g.close();
+        svg.circle().svg_rotate(90.0).apply_transform().r(5);
+        assert_eq!(
+            String::from(tree),
+            "<svg><g transform=\"translate(10 20) rotate(45)\" />\
+            <g id=\"a\" transform=\"scale(2 2) skewX(5)\">\
+            <rect transform=\"skewY(1.5)\" /></g>\
+            <circle transform=\"rotate(90)\" r=\"5\" /></svg>"
+        );
+    }
+
+    #[test]
+    fn pending_mixed() {
+        let mut tree = Tree::new();
+        let mut svg = tree.root::<Svg>();
+        svg.g()
+            .transform("scale(2)")
+            .svg_translate(1.0, 2.0)
+            .close();
+        svg.g()
+            .svg_rotate(45.0)
+            .transform(Transform::skew_x(5.0))
+            .transform(None::<&str>)
+            .close();
+        assert_eq!(
+            String::from(tree),
+            "<svg><g transform=\"scale(2) translate(1 2)\" />\
+            <g transform=\"rotate(45) skewX(5)\" /></svg>"
+        );
+    }
+
+    #[test]
+    fn svg_transform_list() {
+        let mut list = SvgTransformList::new();
+        assert!(list.is_empty());
+        list.push("scale(2)").push("").push(Transform::rotate(5.0));
+        assert_eq!(list.to_string(), "scale(2) rotate(5)");
+    }
+
+    #[test]
+    fn pending_open() {
+        let mut tree = Tree::new();
+        tree.root::<Svg>().svg_translate(1.0, 2.0);
+        assert_eq!(tree.to_string(), "<svg transform=\"translate(1 2)\" />");
+        assert_eq!(tree.as_str(), "<svg");
+        assert!(tree.to_string().starts_with(tree.as_str()));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add transform attribute to <g>")]
+    fn pending_after_content() {
+        let mut tree = Tree::new();
+        let mut g = tree.root::<G>();
+        g.rect().close();
+        g.svg_rotate(45.0);
+    }
+}
@@ -2,9 +2,24 @@
 //
 // Copyright (C) 2025-2026  Douglas P Lau
 //
-use crate::html::Html;
-use crate::value::Value;
-use std::fmt;
+use crate::error::Error;
+use crate::fragment::Fragment;
+use crate::html::{Custom, Html};
+use crate::transform::SvgTransformList;
+#[cfg(feature = "validate")]
+use crate::validate::Validator;
+use crate::value::{
+    AttrWriter, CdataWriter, ControlCharPolicy, EntityStyle, EscapeProfile,
+    Value,
+};
+use std::borrow::Cow;
+use std::fmt::{self, Write};
+
+/// XML declaration
+const XML_DECLARATION: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>";
+
+/// Estimated length of an element with no text, in bytes
+const ELEM_ESTIMATE: usize = 24;
 
 /// Element type
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -17,17 +32,90 @@ pub enum ElemType {
     Xml,
 }
 
+/// Document type declaration
+///
+/// Included by [Tree::html] before the `<html>` element.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Doctype {
+    /// HTML5: `<!DOCTYPE html>`
+    #[default]
+    Html5,
+    /// XHTML 1.0 Strict
+    Xhtml1Strict,
+    /// XHTML 1.0 Transitional
+    Xhtml1Transitional,
+    /// HTML 4.01 Strict
+    Html4Strict,
+    /// Custom declaration (used verbatim)
+    Custom(&'static str),
+}
+
+impl Doctype {
+    /// Get doctype declaration
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Doctype::Html5 => "<!DOCTYPE html>",
+            Doctype::Xhtml1Strict => {
+                "<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Strict//EN\" \
+                \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd\">"
+            }
+            Doctype::Xhtml1Transitional => {
+                "<!DOCTYPE html PUBLIC \
+                \"-//W3C//DTD XHTML 1.0 Transitional//EN\" \
+                \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd\">"
+            }
+            Doctype::Html4Strict => {
+                "<!DOCTYPE HTML PUBLIC \"-//W3C//DTD HTML 4.01//EN\" \
+                \"http://www.w3.org/TR/html4/strict.dtd\">"
+            }
+            Doctype::Custom(doctype) => doctype,
+        }
+    }
+}
+
+impl fmt::Display for Doctype {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// HTML tree builder
 #[derive(Default)]
 pub struct Tree {
     /// HTML document text
     doc: String,
     /// Stack of element tags
-    stack: Vec<&'static str>,
+    stack: Vec<Cow<'static, str>>,
     /// Leaf node element type
     tp: Option<ElemType>,
-    /// Current tag empty
-    empty: bool,
+    /// Innermost start tag is unfinished (no `>` yet), so attributes can be
+    /// appended
+    in_start_tag: bool,
+    /// Last content of innermost element is a closed child element
+    after_child: bool,
+    /// Pending `transform` attribute of innermost start tag
+    transform: SvgTransformList,
+    /// Document type declaration
+    doctype: Doctype,
+    /// XML declaration included
+    xml_decl: bool,
+    /// XML-compatible serialization
+    xml_compatible: bool,
+    /// Attribute value escaping profile
+    escape: EscapeProfile,
+    /// Control character policy
+    control: ControlCharPolicy,
+    /// Character reference style
+    entities: EntityStyle,
+    /// Check raw content (debug builds only)
+    strict_raw: bool,
+    /// Maximum depth of open elements
+    max_depth: Option<usize>,
+    /// Indentation for pretty-printing (empty for compact output)
+    indent: Cow<'static, str>,
+    /// Document validator
+    #[cfg(feature = "validate")]
+    validator: Validator,
 }
 
 /// Renamed to `Tree`; will be removed in a future release
@@ -42,30 +130,36 @@ pub trait Element<'t> {
     /// Element type
     const TP: ElemType;
 
-    /// Make new "root" element
-    fn new(tree: &'t mut Tree) -> Self;
+    /// Make element at a depth
+    fn new(tree: &'t mut Tree, depth: usize) -> Self;
+}
+
+/// Write escaped character data
+///
+/// ```rust
+/// use hatmil::{Tree, html::P};
+/// use std::fmt::Write;
+///
+/// let mut tree = Tree::new();
+/// let _ = tree.root::<P>();
+/// write!(tree, "Score: {} < {}", 3, 5).unwrap();
+/// assert_eq!(String::from(tree), "<p>Score: 3 &lt; 5</p>");
+/// ```
+impl fmt::Write for Tree {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.cdata(s);
+        Ok(())
+    }
+
+    fn write_fmt(&mut self, args: fmt::Arguments) -> fmt::Result {
+        self.cdata_fmt(args);
+        Ok(())
+    }
 }
 
 impl fmt::Display for Tree {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut void = self.tp == Some(ElemType::HtmlVoid);
-        let mut self_closing = self.empty && self.tp == Some(ElemType::Xml);
-        if self_closing && let Some(tree) = self.doc.strip_suffix('>') {
-            write!(f, "{tree}")?;
-        } else {
-            write!(f, "{}", self.doc)?;
-            self_closing = false;
-        }
-        for tag in self.stack.iter().rev() {
-            if self_closing {
-                write!(f, " />")?;
-            } else if !void {
-                write!(f, "</{tag}>")?;
-            }
-            self_closing = false;
-            void = false;
-        }
-        Ok(())
+        self.write_to_fmt(f)
     }
 }
 
@@ -73,6 +167,8 @@ impl From<Tree> for String {
     fn from(mut tree: Tree) -> Self {
         // zero-copy alternative to fmt::Display
         tree.close_to(1);
+        #[cfg(feature = "validate")]
+        tree.validator.check_unresolved();
         tree.doc
     }
 }
@@ -97,342 +193,2503 @@ impl Tree {
         Self::default()
     }
 
-    /// Has no effect; will be removed in a future release
-    #[deprecated]
-    pub fn with_doctype(self) -> Self {
-        self
+    /// Create an HTML tree builder with capacity for document text
+    ///
+    /// - `capacity`: Capacity in bytes
+    ///
+    /// ```rust
+    /// use hatmil::{Tree, html::Ul};
+    ///
+    /// let mut tree = Tree::with_capacity(4096);
+    /// let mut ul = tree.root::<Ul>();
+    /// ul.reserve(1024);
+    /// for i in 0..10 {
+    ///     ul.li().cdata(i).close();
+    /// }
+    /// assert!(tree.len() <= 4096);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Tree {
+            doc: String::with_capacity(capacity),
+            ..Default::default()
+        }
     }
 
-    /// Create `<html>` element
+    /// Create an HTML tree builder with a document type declaration
     ///
-    /// The `<!DOCTYPE html>` preamble will be included.
-    pub fn html(&mut self) -> Html<'_> {
-        self.stack.clear();
-        self.doc.clear();
-        self.raw("<!DOCTYPE html>");
-        self.elem("html", ElemType::Html);
-        Html::new(self)
+    /// ```rust
+    /// use hatmil::{Doctype, Tree};
+    ///
+    /// let mut tree = Tree::for_doctype(Doctype::Xhtml1Strict);
+    /// tree.html().xmlns("http://www.w3.org/1999/xhtml");
+    /// assert!(String::from(tree).starts_with(
+    ///     "<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Strict//EN\""
+    /// ));
+    /// ```
+    pub fn for_doctype(doctype: Doctype) -> Self {
+        Tree {
+            doctype,
+            ..Default::default()
+        }
     }
 
-    /// Create root snippet `E` element
+    /// Create an HTML tree builder with indented output
     ///
-    /// - `E`: Element type, from either the [html] or [svg] modules
+    /// - `indent`: Indentation for each level, such as `"  "`
+    ///
+    /// A newline and indentation are added before each start tag, and before
+    /// the end tag of an element containing other elements.  This is useful
+    /// for debugging, but may change rendering of whitespace-sensitive
+    /// content.
     ///
     /// ```rust
-    /// use hatmil::{Tree, html::A};
+    /// use hatmil::Tree;
     ///
-    /// let mut tree = Tree::new();
-    /// tree.root::<A>().href("https://www.example.com/").cdata("Example link");
+    /// let mut tree = Tree::with_indent("  ");
+    /// tree.html().head().title_el().cdata("hi");
     /// assert_eq!(
     ///     String::from(tree),
-    ///     "<a href=\"https://www.example.com/\">Example link</a>",
+    ///     "<!DOCTYPE html>\n<html>\n  <head>\n    <title>hi</title>\n  </head>\n</html>",
     /// );
     /// ```
-    ///
-    /// [html]: crate::html
-    /// [svg]: crate::svg
-    pub fn root<'t, E>(&'t mut self) -> E
-    where
-        E: Element<'t>,
-    {
-        self.elem(E::TAG, E::TP);
-        E::new(self)
+    pub fn with_indent(indent: impl Into<Cow<'static, str>>) -> Self {
+        Tree {
+            indent: indent.into(),
+            ..Default::default()
+        }
     }
 
-    /// Renamed to `root`; will be removed in a future release
+    /// Has no effect; will be removed in a future release
     #[deprecated]
-    pub fn frag<'t, E>(&'t mut self) -> E
-    where
-        E: Element<'t>,
-    {
-        self.root()
+    pub fn with_doctype(self) -> Self {
+        self
     }
 
-    /// Add an element
+    /// Get the document text as a string slice
     ///
-    /// - `tag`: Element tag
-    /// - `tp`: Element type
+    /// Closing tags for any open elements are **not** included.
     ///
-    /// [Void]: https://developer.mozilla.org/en-US/docs/Glossary/Void_element
-    pub(crate) fn elem(&mut self, tag: &'static str, tp: ElemType) -> usize {
-        self.doc.push('<');
-        self.doc.push_str(tag);
-        self.doc.push('>');
-        self.empty = true;
-        self.tp = Some(tp);
-        self.stack.push(tag);
-        self.stack.len()
+    /// ```rust
+    /// use hatmil::{Tree, html::Div};
+    ///
+    /// let mut tree = Tree::new();
+    /// tree.root::<Div>().cdata("Text");
+    /// assert_eq!(tree.as_str(), "<div>Text");
+    /// ```
+    ///
+    /// The innermost start tag is unfinished until content is added or it
+    /// is closed, so its `>` (and any pending `transform` attribute) is not
+    /// included yet.  The text is always a prefix of the written document.
+    pub fn as_str(&self) -> &str {
+        &self.doc
     }
 
-    /// Add an attribute with value
+    /// Write the document text to a formatter or string
     ///
-    /// These characters will be replaced with entities:
+    /// Closing tags for any open elements are included, as with
+    /// `fmt::Display`.
     ///
-    /// - `&` ⇨ `&amp;`
-    /// - `"` ⇨ `&quot;`
-    pub(crate) fn attr<'a, V>(&mut self, attr: &str, val: V)
-    where
-        V: Into<Value<'a>>,
-    {
-        match self.doc.pop() {
-            Some(gt) => assert_eq!(gt, '>'),
-            None => panic!("cannot add {attr} attribute after child content"),
+    /// ```rust
+    /// use hatmil::{Tree, html::Div};
+    ///
+    /// let mut tree = Tree::new();
+    /// tree.root::<Div>().cdata("Text");
+    /// let mut out = String::from("<body>");
+    /// tree.write_to_fmt(&mut out).unwrap();
+    /// assert_eq!(out, "<body><div>Text</div>");
+    /// ```
+    pub fn write_to_fmt<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        let mut void = self.tp == Some(ElemType::HtmlVoid);
+        let mut start = self.in_start_tag;
+        w.write_str(&self.doc)?;
+        if start && !self.transform.is_empty() {
+            write!(w, " transform=\"{}\"", self.transform)?;
         }
-        self.doc.push(' ');
-        self.doc.push_str(attr);
-        self.doc.push_str("=\"");
-        val.into().encode_attr(&mut self.doc);
-        self.doc.push_str("\">");
+        for tag in self.stack.iter().rev() {
+            if start && self.tp == Some(ElemType::Xml) {
+                w.write_str(" />")?;
+            } else {
+                if start && void && self.xml_compatible {
+                    w.write_str(" />")?;
+                } else if start {
+                    w.write_char('>')?;
+                }
+                if !void {
+                    write!(w, "</{tag}>")?;
+                }
+            }
+            start = false;
+            void = false;
+        }
+        Ok(())
     }
 
-    /// Add a [Boolean] attribute
+    /// Get the length of the document text, in bytes
     ///
-    /// [Boolean]: https://developer.mozilla.org/en-US/docs/Glossary/Boolean/HTML
-    pub(crate) fn attr_bool(&mut self, attr: &'static str) {
-        match self.doc.pop() {
-            Some(gt) => assert_eq!(gt, '>'),
-            None => panic!("cannot add {attr} attribute after child content"),
+    /// This is the length of [as_str](Tree::as_str), so closing tags for any
+    /// open elements and the end of an unfinished start tag are **not**
+    /// included.
+    pub fn len(&self) -> usize {
+        self.doc.len()
+    }
+
+    /// Check if the document text is empty
+    pub fn is_empty(&self) -> bool {
+        self.doc.is_empty()
+    }
+
+    /// Get the depth of open elements
+    ///
+    /// ```rust
+    /// use hatmil::{Tree, html::Div};
+    ///
+    /// let mut tree = Tree::new();
+    /// let mut div = tree.root::<Div>();
+    /// let _ = div.ul().li();
+    /// assert_eq!(tree.depth(), 3);
+    /// assert_eq!(tree.current_path(), "div > ul > li");
+    /// tree.close();
+    /// assert!(tree.open_tags().eq(["div", "ul"]));
+    /// ```
+    pub fn depth(&self) -> usize {
+        self.open_stack().len()
+    }
+
+    /// Get an iterator of open element tags, from the root
+    pub fn open_tags(&self) -> impl Iterator<Item = &str> {
+        self.open_stack().iter().map(|tag| &tag[..])
+    }
+
+    /// Get the tag of the innermost open element
+    pub fn current_tag(&self) -> Option<&str> {
+        self.open_stack().last().map(|tag| &tag[..])
+    }
+
+    /// Get the stack of open elements
+    ///
+    /// A void element has no content, so it is never open.
+    fn open_stack(&self) -> &[Cow<'static, str>] {
+        match self.tp {
+            Some(ElemType::HtmlVoid) => {
+                &self.stack[..self.stack.len().saturating_sub(1)]
+            }
+            _ => &self.stack,
         }
-        self.doc.push(' ');
-        self.doc.push_str(attr);
-        self.doc.push('>');
     }
 
-    /// Add a comment
+    /// Get the path of open element tags (e.g. `html > body > div`)
+    pub fn current_path(&self) -> String {
+        self.open_tags().collect::<Vec<_>>().join(" > ")
+    }
+
+    /// Get same-document fragment references with no matching `id`
     ///
-    /// These characters will be replaced with entities:
+    /// References are tracked from `href="#…"` attributes (HTML or SVG), as
+    /// well as `aria-labelledby` and `aria-describedby` token lists.
     ///
-    /// - `-` ⇨ `&hyphen;`
-    /// - `<` ⇨ `&lt;`
-    /// - `>` ⇨ `&gt;`
-    pub fn comment<'a, V>(&mut self, com: V) -> &mut Self
-    where
-        V: Into<Value<'a>>,
-    {
-        self.doc.push_str("<!--");
-        com.into().encode_comment(&mut self.doc);
-        self.doc.push_str("-->");
-        self.empty = false;
-        self
+    /// ```rust
+    /// use hatmil::{Tree, html::Div};
+    ///
+    /// let mut tree = Tree::new();
+    /// let mut div = tree.root::<Div>();
+    /// div.a().href("#intro").cdata("Intro").close();
+    /// div.a().href("#summary").cdata("Summary").close();
+    /// div.h2().id("intro").cdata("Introduction");
+    /// assert_eq!(tree.unresolved_fragments(), ["summary"]);
+    /// ```
+    #[cfg(feature = "validate")]
+    pub fn unresolved_fragments(&self) -> Vec<String> {
+        self.validator.unresolved_fragments()
     }
 
-    /// Add character data content
-    pub(crate) fn cdata<'a, V>(&mut self, text: V) -> &mut Self
-    where
-        V: Into<Value<'a>>,
-    {
-        text.into().encode_cdata(&mut self.doc);
-        self.empty = false;
+    /// Set panic on unresolved fragments mode
+    ///
+    /// When enabled, converting the tree into a `String` will panic if any
+    /// [unresolved fragments] remain.
+    ///
+    /// [unresolved fragments]: Self::unresolved_fragments
+    #[cfg(feature = "validate")]
+    pub fn panic_unresolved(&mut self, panic: bool) -> &mut Self {
+        self.validator.set_panic_unresolved(panic);
         self
     }
 
-    /// Add character data content with a maximum character limit
-    pub(crate) fn cdata_len<'a, V>(&mut self, text: V, len: usize) -> &mut Self
-    where
-        V: Into<Value<'a>>,
-    {
-        text.into().encode_cdata_len(&mut self.doc, len);
-        self.empty = false;
+    /// Reserve capacity for additional document text, in bytes
+    pub fn reserve(&mut self, additional: usize) -> &mut Self {
+        self.doc.reserve(additional);
         self
     }
 
-    /// Add raw content
+    /// Reserve capacity for an estimated document size
     ///
-    /// **WARNING**: `trusted` is used verbatim, with no escaping; do not call
-    /// with untrusted content.
-    pub fn raw(&mut self, trusted: impl AsRef<str>) -> &mut Self {
-        self.doc.push_str(trusted.as_ref());
-        self.empty = false;
+    /// - `elements`: Number of elements
+    /// - `avg_text`: Average text length per element, in bytes
+    ///
+    /// This can avoid repeated reallocation when building large documents.
+    pub fn estimate_and_reserve(
+        &mut self,
+        elements: usize,
+        avg_text: usize,
+    ) -> &mut Self {
+        self.doc
+            .reserve(elements.saturating_mul(ELEM_ESTIMATE + avg_text));
         self
     }
 
-    /// Close elements to the specified depth
-    pub(crate) fn close_to(&mut self, depth: usize) -> &mut Self {
-        while self.stack.len() >= depth {
-            self.close();
-        }
+    cond_methods!();
+
+    /// Reset the tree, keeping allocated capacity
+    ///
+    /// All content is cleared, including any XML declaration.  The doctype
+    /// and XML-compatible mode are preserved.
+    ///
+    /// ```rust
+    /// use hatmil::{Tree, html::P};
+    ///
+    /// let mut tree = Tree::new();
+    /// tree.root::<P>().cdata("First");
+    /// tree.reset();
+    /// tree.root::<P>().cdata("Second");
+    /// assert_eq!(tree.to_string(), "<p>Second</p>");
+    /// ```
+    pub fn reset(&mut self) -> &mut Self {
+        self.doc.clear();
+        self.stack.clear();
+        self.tp = None;
+        self.in_start_tag = false;
+        self.after_child = false;
+        self.transform = SvgTransformList::new();
+        self.xml_decl = false;
+        #[cfg(feature = "validate")]
+        self.validator.clear();
         self
     }
 
-    /// Close the final open element
+    /// Add an XML declaration
     ///
-    /// Add a closing tag (e.g. `</span>`).
-    pub fn close(&mut self) -> &mut Self {
-        let tp = self.tp.take();
-        if let Some(tag) = self.stack.pop() {
-            let void = tp == Some(ElemType::HtmlVoid);
-            let self_closing = self.empty && tp == Some(ElemType::Xml);
-            if self_closing && self.doc.ends_with('>') {
-                self.doc.pop();
-                self.doc.push_str(" />");
-            } else if !void {
-                self.doc.push_str("</");
-                self.doc.push_str(tag);
-                self.doc.push('>');
-            }
+    /// `<?xml version="1.0" encoding="UTF-8"?>` is needed at the top of
+    /// standalone SVG or XHTML files.
+    ///
+    /// ```rust
+    /// use hatmil::{Tree, svg::Svg};
+    ///
+    /// let mut tree = Tree::new();
+    /// tree.xml_declaration();
+    /// tree.root::<Svg>().xmlns("http://www.w3.org/2000/svg");
+    /// assert_eq!(
+    ///     String::from(tree),
+    ///     "<?xml version=\"1.0\" encoding=\"UTF-8\"?><svg xmlns=\"http://www.w3.org/2000/svg\" />",
+    /// );
+    /// ```
+    ///
+    /// This must be called before any content is added, or it will panic.
+    pub fn xml_declaration(&mut self) -> &mut Self {
+        if !self.is_empty() {
+            panic!("XML declaration must precede all content");
         }
-        self.empty = false;
-        self
+        self.xml_decl = true;
+        self.raw(XML_DECLARATION)
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::html::*;
+    /// Write the document type declaration
+    ///
+    /// This is only needed when the root element is not created with
+    /// [html](Self::html), which includes it automatically.
+    ///
+    /// ```rust
+    /// use hatmil::{Tree, html::Html};
+    ///
+    /// let mut tree = Tree::new();
+    /// tree.write_doctype().root::<Html>().lang("en");
+    /// assert_eq!(
+    ///     String::from(tree),
+    ///     "<!DOCTYPE html><html lang=\"en\"></html>",
+    /// );
+    /// ```
+    ///
+    /// This must be called before any content other than an XML declaration
+    /// is added, or it will panic.
+    pub fn write_doctype(&mut self) -> &mut Self {
+        if !self.is_empty() && self.doc != XML_DECLARATION {
+            panic!("document type declaration must precede all content");
+        }
+        self.raw(self.doctype.as_str())
+    }
 
-    #[test]
-    fn div() {
-        let mut tree = Tree::new();
-        tree.root::<Div>();
-        assert_eq!(tree.to_string(), "<div></div>");
+    /// Enable XML-compatible serialization
+    ///
+    /// Boolean attributes are written as `name="name"`, and void elements are
+    /// self-closed, as required by XHTML.
+    ///
+    /// ```rust
+    /// use hatmil::{Tree, html::Input};
+    ///
+    /// let mut tree = Tree::new();
+    /// tree.xml_compatible();
+    /// tree.root::<Input>().disabled();
+    /// assert_eq!(String::from(tree), "<input disabled=\"disabled\" />");
+    /// ```
+    pub fn xml_compatible(&mut self) -> &mut Self {
+        self.xml_compatible = true;
+        self
     }
 
-    #[test]
-    fn boolean() {
-        let mut tree = Tree::new();
-        tree.root::<Div>().id("test").spellcheck(true);
-        assert_eq!(
-            tree.to_string(),
-            "<div id=\"test\" spellcheck=\"true\"></div>"
-        );
+    /// Set the attribute value escaping profile
+    ///
+    /// The default is [EscapeProfile::Minimal].
+    pub fn escape_quotes(&mut self, profile: EscapeProfile) -> &mut Self {
+        self.escape = profile;
+        self
     }
 
-    #[test]
-    fn paragraph() {
-        let mut tree = Tree::new();
-        tree.root::<P>().cdata("This is a paragraph");
-        assert_eq!(tree.to_string(), "<p>This is a paragraph</p>");
+    /// Set the character reference style
+    ///
+    /// The default is [EntityStyle::Named].  [EntityStyle::Numeric] is useful
+    /// for XML, which does not define HTML entities such as `&hyphen;`.
+    pub fn entity_style(&mut self, style: EntityStyle) -> &mut Self {
+        self.entities = style;
+        self
     }
 
-    #[test]
-    fn escape_attr() {
-        let mut tree = Tree::new();
-        tree.root::<P>().id("quote\"ampersand&");
-        assert_eq!(
-            tree.to_string(),
-            "<p id=\"quote&quot;ampersand&amp;\"></p>"
-        );
+    /// Set the policy for control characters in text and attribute values
+    ///
+    /// The default is [ControlCharPolicy::Keep].
+    pub fn control_chars(&mut self, policy: ControlCharPolicy) -> &mut Self {
+        self.control = policy;
+        self
     }
 
-    #[test]
-    fn escape_cdata() {
+    /// Check raw content for broken markup
+    ///
+    /// In debug builds, [raw](Self::raw) will panic if the content has
+    /// unbalanced `<` / `>`, closes an element which is not open, or contains
+    /// a `<script` tag.  Release builds do not check.
+    ///
+    /// ```rust
+    /// use hatmil::{Tree, html::P};
+    ///
+    /// let mut tree = Tree::new();
+    /// tree.strict_raw();
+    /// tree.root::<P>().raw("<b>bold</b> &amp; <i>italic</i>");
+    /// assert_eq!(String::from(tree), "<p><b>bold</b> &amp; <i>italic</i></p>");
+    /// ```
+    pub fn strict_raw(&mut self) -> &mut Self {
+        self.strict_raw = true;
+        self
+    }
+
+    /// Set the maximum depth of open elements
+    ///
+    /// Adding an element beyond this depth will panic, or return
+    /// [Error::DepthExceeded] from `try_custom`.
+    ///
+    /// ```rust
+    /// use hatmil::{Error, Tree, html::Div};
+    ///
+    /// let mut tree = Tree::new();
+    /// tree.max_depth(2);
+    /// let mut div = tree.root::<Div>();
+    /// let mut item = div.try_custom("x-item")?;
+    /// assert_eq!(item.try_custom("x-sub").err(), Some(Error::DepthExceeded(2)));
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn max_depth(&mut self, depth: usize) -> &mut Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Check that another element can be added within the maximum depth
+    fn check_depth(&self) -> Result<(), Error> {
+        match self.max_depth {
+            Some(max) if self.open_stack().len() >= max => {
+                Err(Error::DepthExceeded(max))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Create `<html>` element
+    ///
+    /// The document type declaration (`<!DOCTYPE html>` by default) will be
+    /// included, following the XML declaration if one was added.  Any
+    /// previous content is replaced.
+    #[must_use]
+    pub fn html(&mut self) -> Html<'_> {
+        self.stack.clear();
+        self.doc.clear();
+        self.tp = None;
+        self.in_start_tag = false;
+        self.after_child = false;
+        self.transform = SvgTransformList::new();
+        #[cfg(feature = "validate")]
+        self.validator.clear();
+        if self.xml_decl {
+            self.raw(XML_DECLARATION);
+        }
+        self.raw(self.doctype.as_str());
+        self.elem("html", ElemType::Html);
+        Html::new(self, 1)
+    }
+
+    /// Create root snippet `E` element
+    ///
+    /// - `E`: Element type, from either the [html] or [svg] modules
+    ///
+    /// ```rust
+    /// use hatmil::{Tree, html::A};
+    ///
+    /// let mut tree = Tree::new();
+    /// tree.root::<A>().href("https://www.example.com/").cdata("Example link");
+    /// assert_eq!(
+    ///     String::from(tree),
+    ///     "<a href=\"https://www.example.com/\">Example link</a>",
+    /// );
+    /// ```
+    ///
+    /// [html]: crate::html
+    /// [svg]: crate::svg
+    #[must_use]
+    pub fn root<'t, E>(&'t mut self) -> E
+    where
+        E: Element<'t>,
+    {
+        self.elem(E::TAG, E::TP);
+        E::new(self, 1)
+    }
+
+    /// Resume building the innermost open `E` element
+    ///
+    /// Panics if the innermost open element is not `E`.
+    ///
+    /// ```rust
+    /// use hatmil::{Tree, html::Ul};
+    ///
+    /// let mut tree = Tree::new();
+    /// tree.root::<Ul>().li().cdata("one").close();
+    /// tree.resume::<Ul>().li().cdata("two");
+    /// assert_eq!(
+    ///     String::from(tree),
+    ///     "<ul><li>one</li><li>two</li></ul>",
+    /// );
+    /// ```
+    #[must_use]
+    pub fn resume<'t, E>(&'t mut self) -> E
+    where
+        E: Element<'t>,
+    {
+        let tag = self.current_tag();
+        if tag != Some(E::TAG) {
+            panic!("cannot resume <{}>: innermost is {tag:?}", E::TAG);
+        }
+        let depth = self.depth();
+        E::new(self, depth)
+    }
+
+    /// Take a snapshot of the tree
+    ///
+    /// The snapshot includes document text, open elements and settings, so
+    /// it can be built separately from the original (see [Tree::resume]).
+    ///
+    /// ```rust
+    /// use hatmil::{Tree, html::Ul};
+    ///
+    /// let mut tree = Tree::new();
+    /// tree.root::<Ul>().li().cdata("one").close();
+    /// let mut snap = tree.snapshot();
+    /// tree.resume::<Ul>().li().cdata("two");
+    /// snap.resume::<Ul>().li().cdata("three");
+    /// assert_eq!(String::from(tree), "<ul><li>one</li><li>two</li></ul>");
+    /// assert_eq!(String::from(snap), "<ul><li>one</li><li>three</li></ul>");
+    /// ```
+    pub fn snapshot(&self) -> Self {
+        Tree {
+            doc: self.doc.clone(),
+            stack: self.stack.clone(),
+            tp: self.tp,
+            in_start_tag: self.in_start_tag,
+            after_child: self.after_child,
+            transform: self.transform.clone(),
+            doctype: self.doctype,
+            xml_decl: self.xml_decl,
+            xml_compatible: self.xml_compatible,
+            escape: self.escape,
+            control: self.control,
+            entities: self.entities,
+            strict_raw: self.strict_raw,
+            max_depth: self.max_depth,
+            indent: self.indent.clone(),
+            #[cfg(feature = "validate")]
+            validator: self.validator.clone(),
+        }
+    }
+
+    /// Add a JSON data `script` element
+    ///
+    /// - `id`: Element ID
+    /// - `value`: Value to serialize (see [Script::json])
+    ///
+    /// ```rust
+    /// use hatmil::{Tree, html::Body};
+    ///
+    /// let mut tree = Tree::new();
+    /// let _ = tree.root::<Body>();
+    /// tree.json_script("data", &[1, 2])?;
+    /// assert_eq!(
+    ///     String::from(tree),
+    ///     "<body><script type=\"application/json\" id=\"data\">[1,2]</script></body>",
+    /// );
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    ///
+    /// [Script::json]: crate::html::Script::json
+    #[cfg(feature = "serde_json")]
+    pub fn json_script<T>(
+        &mut self,
+        id: &str,
+        value: &T,
+    ) -> serde_json::Result<&mut Self>
+    where
+        T: serde::Serialize + ?Sized,
+    {
+        let json = crate::value::encode_json(value)?;
+        let depth = self.elem("script", ElemType::Html);
+        self.attr(depth, "type", "application/json");
+        self.attr(depth, "id", id);
+        self.raw_text_escaped("script", json);
+        Ok(self.close())
+    }
+
+    /// Create root custom element
+    ///
+    /// - `tag`: Element tag, which must contain a hyphen and only lower-case
+    ///   ASCII letters, digits and hyphens
+    ///
+    /// ```rust
+    /// use hatmil::Tree;
+    ///
+    /// let mut tree = Tree::new();
+    /// tree.custom("my-widget").id("w").cdata("Widget");
+    /// assert_eq!(
+    ///     String::from(tree),
+    ///     "<my-widget id=\"w\">Widget</my-widget>",
+    /// );
+    /// ```
+    #[must_use]
+    pub fn custom(&mut self, tag: impl Into<Cow<'static, str>>) -> Custom<'_> {
+        let depth = self.elem_custom(tag.into());
+        Custom { tree: self, depth }
+    }
+
+    /// Create root custom element, checking its name
+    ///
+    /// Returns [Error::InvalidElementName] if `tag` is not a valid custom
+    /// element name.
+    pub fn try_custom(
+        &mut self,
+        tag: impl Into<Cow<'static, str>>,
+    ) -> Result<Custom<'_>, Error> {
+        let depth = self.try_elem_custom(tag.into())?;
+        Ok(Custom { tree: self, depth })
+    }
+
+    /// Renamed to `root`; will be removed in a future release
+    #[deprecated]
+    pub fn frag<'t, E>(&'t mut self) -> E
+    where
+        E: Element<'t>,
+    {
+        self.root()
+    }
+
+    /// Add an element
+    ///
+    /// - `tag`: Element tag
+    /// - `tp`: Element type
+    ///
+    /// [Void]: https://developer.mozilla.org/en-US/docs/Glossary/Void_element
+    pub(crate) fn elem(
+        &mut self,
+        tag: impl Into<Cow<'static, str>>,
+        tp: ElemType,
+    ) -> usize {
+        let tag = tag.into();
+        if let Err(e) = self.check_depth() {
+            panic!("{e}");
+        }
+        self.end_start_tag();
+        if !self.doc.is_empty() {
+            self.push_indent();
+        }
+        self.doc.reserve(tag.len() * 2 + 5);
+        self.doc.push('<');
+        self.doc.push_str(&tag);
+        self.tp = Some(tp);
+        self.in_start_tag = true;
+        #[cfg(feature = "validate")]
+        self.validator.elem();
+        self.stack.push(tag);
+        self.stack.len()
+    }
+
+    /// Add a custom element
+    ///
+    /// Panics if `tag` is not a valid custom element name.
+    pub(crate) fn elem_custom(&mut self, tag: Cow<'static, str>) -> usize {
+        self.try_elem_custom(tag).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Add a custom element, checking its name
+    pub(crate) fn try_elem_custom(
+        &mut self,
+        tag: Cow<'static, str>,
+    ) -> Result<usize, Error> {
+        if !is_custom_name(&tag) {
+            return Err(Error::InvalidElementName(tag.into_owned()));
+        }
+        self.check_depth()?;
+        Ok(self.elem(tag, ElemType::Html))
+    }
+
+    /// Check that an element is the innermost open element
+    ///
+    /// - `depth`: Depth of parent element
+    /// - `child`: Child tag (or content type)
+    ///
+    /// Panics if another element is still open within the parent.
+    #[cfg(feature = "validate")]
+    pub(crate) fn check_parent(&self, depth: usize, child: &str) {
+        if let Err(e) = self.try_check_parent(depth, child) {
+            panic!("{e}");
+        }
+    }
+
+    /// Check that an element is the innermost open element
+    ///
+    /// Returns [Error::WrongParent] if another element is still open within
+    /// the parent.
+    #[cfg(feature = "validate")]
+    pub(crate) fn try_check_parent(
+        &self,
+        depth: usize,
+        child: &str,
+    ) -> Result<(), Error> {
+        let len = self.open_stack().len();
+        if len != depth {
+            let tag = |d: usize| self.stack.get(d - 1).map_or("?", |t| t);
+            return Err(Error::WrongParent {
+                parent: tag(depth).to_string(),
+                open: tag(len).to_string(),
+                child: child.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Add an attribute with value
+    ///
+    /// - `depth`: Depth of element to add the attribute to
+    /// - `attr`: Attribute name
+    /// - `val`: Attribute value (skipped if `None`)
+    ///
+    /// The name is only checked in debug builds, since it comes from a
+    /// generated method.
+    ///
+    /// These characters will be replaced with entities:
+    ///
+    /// - `&` ⇨ `&amp;`
+    /// - `"` ⇨ `&quot;`
+    pub(crate) fn attr<'a, V>(&mut self, depth: usize, attr: &str, val: V)
+    where
+        V: Into<Value<'a>>,
+    {
+        debug_assert!(is_attr_name(attr), "invalid attribute name: {attr:?}");
+        if let Err(e) = self.attr_value(depth, attr, val) {
+            panic!("{e}");
+        }
+    }
+
+    /// Add an attribute with value, checking for errors
+    ///
+    /// - `depth`: Depth of element to add the attribute to
+    /// - `attr`: Attribute name
+    /// - `val`: Attribute value (skipped if `None`)
+    pub(crate) fn try_attr<'a, V>(
+        &mut self,
+        depth: usize,
+        attr: &str,
+        val: V,
+    ) -> Result<(), Error>
+    where
+        V: Into<Value<'a>>,
+    {
+        if !is_attr_name(attr) {
+            return Err(Error::InvalidAttributeName(attr.to_string()));
+        }
+        self.attr_value(depth, attr, val)
+    }
+
+    /// Add an attribute with value, without checking its name
+    fn attr_value<'a, V>(
+        &mut self,
+        depth: usize,
+        attr: &str,
+        val: V,
+    ) -> Result<(), Error>
+    where
+        V: Into<Value<'a>>,
+    {
+        let val = val.into();
+        if val.is_none() {
+            return Ok(());
+        }
+        self.attr_start(depth, attr)?;
+        self.doc.push_str(attr);
+        self.doc.push_str("=\"");
+        self.doc.reserve(val.len_hint() + 2);
+        #[cfg(feature = "validate")]
+        self.validator.attr(attr, val.as_str());
+        let start = self.doc.len();
+        val.encode_attr_profile(&mut self.doc, self.escape, self.entities);
+        self.control.apply(&mut self.doc, start);
+        self.doc.push('"');
+        Ok(())
+    }
+
+    /// Add a `data-*` attribute, checking its name
+    ///
+    /// - `depth`: Depth of element to add the attribute to
+    /// - `name`: Name, without `data-` prefix
+    /// - `val`: Attribute value
+    pub(crate) fn try_attr_data<'a, V>(
+        &mut self,
+        depth: usize,
+        name: &str,
+        val: V,
+    ) -> Result<(), Error>
+    where
+        V: Into<Value<'a>>,
+    {
+        let attr = format!("data-{name}");
+        if !is_data_name(name) {
+            return Err(Error::InvalidAttributeName(attr));
+        }
+        self.attr_value(depth, &attr, val)
+    }
+
+    /// Add a URL attribute, rejecting `javascript:` URLs
+    #[cfg(feature = "url")]
+    pub(crate) fn try_attr_url(
+        &mut self,
+        depth: usize,
+        attr: &str,
+        url: &::url::Url,
+    ) -> Result<(), Error> {
+        if url.scheme() == "javascript" {
+            return Err(Error::DisallowedUrlScheme(url.scheme().to_string()));
+        }
+        self.attr_value(depth, attr, url)
+    }
+
+    /// Add an attribute with formatted value, checking for errors
+    ///
+    /// The value is escaped while formatting, with no intermediate `String`.
+    pub(crate) fn try_attr_fmt(
+        &mut self,
+        depth: usize,
+        attr: &str,
+        args: fmt::Arguments,
+    ) -> Result<(), Error> {
+        if !is_attr_name(attr) {
+            return Err(Error::InvalidAttributeName(attr.to_string()));
+        }
+        self.attr_start(depth, attr)?;
+        self.doc.push_str(attr);
+        self.doc.push_str("=\"");
+        #[cfg(feature = "validate")]
+        self.validator.attr(attr, &args.to_string());
+        let start = self.doc.len();
+        AttrWriter(&mut self.doc, self.escape, self.entities)
+            .write_fmt(args)
+            .expect("Display implementation returned an error");
+        self.control.apply(&mut self.doc, start);
+        self.doc.push('"');
+        Ok(())
+    }
+
+    /// Add a [Boolean] attribute
+    ///
+    /// - `depth`: Depth of element to add the attribute to
+    /// - `attr`: Attribute name
+    ///
+    /// [Boolean]: https://developer.mozilla.org/en-US/docs/Glossary/Boolean/HTML
+    pub(crate) fn attr_bool(&mut self, depth: usize, attr: &'static str) {
+        if let Err(e) = self.attr_start(depth, attr) {
+            panic!("{e}");
+        }
+        self.doc.push_str(attr);
+        if self.xml_compatible {
+            self.doc.push_str("=\"");
+            self.doc.push_str(attr);
+            self.doc.push('"');
+        }
+    }
+
+    /// Start adding an attribute to an element's start tag
+    ///
+    /// The element must be the innermost open element, with no content.
+    fn attr_start(&mut self, depth: usize, attr: &str) -> Result<(), Error> {
+        if self.stack.len() != depth || !self.in_start_tag {
+            let tag = self.stack.get(depth - 1).map_or("?", |t| t);
+            return Err(Error::AttributeAfterContent {
+                tag: tag.to_string(),
+                attr: attr.to_string(),
+            });
+        }
+        #[cfg(feature = "validate")]
+        if let Some(tag) = self.stack.last() {
+            self.validator.attr_name(tag, attr)?;
+        }
+        self.doc.push(' ');
+        Ok(())
+    }
+
+    /// Finish the innermost start tag, adding any pending `transform`
+    ///
+    /// Void elements are self-closed (` />`) for XML-compatible
+    /// serialization.
+    fn finish_start_tag(&mut self) {
+        if self.in_start_tag {
+            self.apply_transform();
+            self.in_start_tag = false;
+            if self.xml_compatible && self.tp == Some(ElemType::HtmlVoid) {
+                self.doc.push_str(" />");
+            } else {
+                self.doc.push('>');
+            }
+        }
+    }
+
+    /// Add transform functions to the pending `transform` attribute
+    ///
+    /// - `depth`: Depth of element to add the transform to
+    /// - `t`: Transform functions
+    ///
+    /// Panics if the element's start tag has ended.
+    pub(crate) fn push_transform(
+        &mut self,
+        depth: usize,
+        t: impl fmt::Display,
+    ) {
+        if self.stack.len() != depth || !self.in_start_tag {
+            let tag = self.stack.get(depth - 1).map_or("?", |t| t);
+            panic!(
+                "{}",
+                Error::AttributeAfterContent {
+                    tag: tag.to_string(),
+                    attr: "transform".to_string(),
+                }
+            );
+        }
+        self.transform.push(t);
+    }
+
+    /// Add the pending `transform` attribute, if any
+    pub(crate) fn apply_transform(&mut self) {
+        if !self.transform.is_empty() {
+            let list = std::mem::take(&mut self.transform);
+            self.attr(self.stack.len(), "transform", list);
+        }
+    }
+
+    /// Add a comment
+    ///
+    /// Single hyphens are unchanged, but a space is inserted between
+    /// consecutive hyphens and after a trailing hyphen.  These characters
+    /// will be replaced with entities:
+    ///
+    /// - `<` ⇨ `&lt;`
+    /// - `>` ⇨ `&gt;`
+    ///
+    /// ```rust
+    /// use hatmil::Tree;
+    ///
+    /// let mut tree = Tree::new();
+    /// tree.comment("<well-known> -->");
+    /// assert_eq!(tree.as_str(), "<!--&lt;well-known&gt; - -&gt;-->");
+    /// ```
+    pub fn comment<'a, V>(&mut self, com: V) -> &mut Self
+    where
+        V: Into<Value<'a>>,
+    {
+        let com = com.into();
+        self.end_start_tag();
+        self.doc.reserve(com.len_hint() + 7);
+        self.doc.push_str("<!--");
+        let start = self.doc.len();
+        com.encode_comment_style(&mut self.doc, self.entities);
+        self.control.apply(&mut self.doc, start);
+        self.doc.push_str("-->");
+        self
+    }
+
+    /// Add a comment, replacing all hyphens
+    ///
+    /// These characters will be replaced with entities:
+    ///
+    /// - `-` ⇨ `&hyphen;`
+    /// - `<` ⇨ `&lt;`
+    /// - `>` ⇨ `&gt;`
+    pub fn comment_strict<'a, V>(&mut self, com: V) -> &mut Self
+    where
+        V: Into<Value<'a>>,
+    {
+        let com = com.into();
+        self.end_start_tag();
+        self.doc.reserve(com.len_hint() + 7);
+        self.doc.push_str("<!--");
+        let start = self.doc.len();
+        com.encode_comment_strict_style(&mut self.doc, self.entities);
+        self.control.apply(&mut self.doc, start);
+        self.doc.push_str("-->");
+        self
+    }
+
+    /// Add character data content
+    pub(crate) fn cdata<'a, V>(&mut self, text: V) -> &mut Self
+    where
+        V: Into<Value<'a>>,
+    {
+        let text = text.into();
+        self.end_start_tag();
+        self.doc.reserve(text.len_hint());
+        let start = self.doc.len();
+        text.encode_cdata_style(&mut self.doc, self.entities);
+        self.control.apply(&mut self.doc, start);
+        self
+    }
+
+    /// Add character data content with a maximum character limit
+    pub(crate) fn cdata_len<'a, V>(&mut self, text: V, len: usize) -> &mut Self
+    where
+        V: Into<Value<'a>>,
+    {
+        self.end_start_tag();
+        let start = self.doc.len();
+        text.into()
+            .encode_cdata_len_style(&mut self.doc, len, self.entities);
+        self.control.apply(&mut self.doc, start);
+        self
+    }
+
+    /// Add raw text content of a `script` or `style` element
+    ///
+    /// Closing tag and comment sequences are escaped.
+    pub(crate) fn raw_text_escaped<'a, V>(
+        &mut self,
+        tag: &str,
+        text: V,
+    ) -> &mut Self
+    where
+        V: Into<Value<'a>>,
+    {
+        let text = text.into();
+        self.end_start_tag();
+        self.doc.reserve(text.len_hint());
+        let start = self.doc.len();
+        text.encode_raw_text(&mut self.doc, tag);
+        self.control.apply(&mut self.doc, start);
+        self
+    }
+
+    /// Add formatted character data content
+    ///
+    /// The text is escaped while formatting, with no intermediate `String`.
+    pub(crate) fn cdata_fmt(&mut self, args: fmt::Arguments) -> &mut Self {
+        self.end_start_tag();
+        let start = self.doc.len();
+        CdataWriter(&mut self.doc, self.entities)
+            .write_fmt(args)
+            .expect("Display implementation returned an error");
+        self.control.apply(&mut self.doc, start);
+        self
+    }
+
+    /// Add character data content, truncated with a suffix
+    pub(crate) fn cdata_truncate<'a, V>(
+        &mut self,
+        text: V,
+        len: usize,
+        suffix: &str,
+    ) -> &mut Self
+    where
+        V: Into<Value<'a>>,
+    {
+        self.end_start_tag();
+        let start = self.doc.len();
+        text.into().encode_cdata_truncate_style(
+            &mut self.doc,
+            len,
+            suffix,
+            self.entities,
+        );
+        self.control.apply(&mut self.doc, start);
+        self
+    }
+
+    /// Add raw content
+    ///
+    /// **WARNING**: `trusted` is used verbatim, with no escaping; do not call
+    /// with untrusted content.
+    ///
+    /// ```rust
+    /// use hatmil::{Tree, html::P};
+    ///
+    /// let mut tree = Tree::new();
+    /// tree.root::<P>().raw("&copy; 2026");
+    /// assert_eq!(String::from(tree), "<p>&copy; 2026</p>");
+    /// ```
+    pub fn raw(&mut self, trusted: impl AsRef<str>) -> &mut Self {
+        self.end_start_tag();
+        #[cfg(debug_assertions)]
+        if self.strict_raw
+            && let Err(e) = check_raw(trusted.as_ref(), &self.stack, false)
+        {
+            panic!("{e}");
+        }
+        self.doc.push_str(trusted.as_ref());
+        self
+    }
+
+    /// Add raw content, checking for broken markup
+    ///
+    /// Returns [Error::InvalidRaw] if the content has unbalanced `<` / `>`,
+    /// closes an element which is not open, or contains a `<script` tag.
+    /// Unlike [strict_raw](Self::strict_raw), this is checked in release
+    /// builds.
+    ///
+    /// ```rust
+    /// use hatmil::{Tree, html::P};
+    ///
+    /// let mut tree = Tree::new();
+    /// let mut p = tree.root::<P>();
+    /// assert!(p.try_raw_checked("</div>").is_err());
+    /// p.try_raw_checked("<b>bold</b>")?;
+    /// assert_eq!(String::from(tree), "<p><b>bold</b></p>");
+    /// # Ok::<(), hatmil::Error>(())
+    /// ```
+    pub fn try_raw_checked(
+        &mut self,
+        trusted: impl AsRef<str>,
+    ) -> Result<&mut Self, Error> {
+        check_raw(trusted.as_ref(), self.open_stack(), false)?;
+        self.end_start_tag();
+        self.doc.push_str(trusted.as_ref());
+        Ok(self)
+    }
+
+    /// Add pre-sanitized HTML content
+    ///
+    /// `html` is used verbatim, like [raw](Self::raw), but should be the
+    /// output of an HTML sanitizer.  In debug builds, this will panic if it
+    /// has an unclosed element (other than one with an optional end tag, such
+    /// as `<li>` or `<p>`), or contains a `<script` tag.
+    ///
+    /// ```rust
+    /// use hatmil::{Tree, html::P};
+    ///
+    /// let mut tree = Tree::new();
+    /// tree.root::<P>().fragment_str("<strong>bold</strong> text");
+    /// assert_eq!(String::from(tree), "<p><strong>bold</strong> text</p>");
+    /// ```
+    pub fn fragment_str(&mut self, html: &str) -> &mut Self {
+        self.end_start_tag();
+        #[cfg(debug_assertions)]
+        if let Err(e) = check_raw(html, &[], true) {
+            panic!("{e}");
+        }
+        self.doc.push_str(html);
+        self
+    }
+
+    /// Push a newline and indentation for the current depth
+    fn push_indent(&mut self) {
+        if !self.indent.is_empty() {
+            self.doc.push('\n');
+            for _ in 0..self.stack.len() {
+                self.doc.push_str(&self.indent);
+            }
+        }
+    }
+
+    /// End the innermost start tag, before adding content
+    ///
+    /// Void elements have no closing tag or content, so they are also popped
+    /// from the stack.
+    fn end_start_tag(&mut self) {
+        self.finish_start_tag();
+        self.after_child = false;
+        if self.tp == Some(ElemType::HtmlVoid) {
+            self.tp = None;
+            self.stack.pop();
+        }
+    }
+
+    /// Pop a void element from the stack
+    ///
+    /// Void elements have no closing tag, so they are not checked by
+    /// `try_close` or `try_close_tag`.
+    fn pop_void(&mut self) {
+        if self.tp == Some(ElemType::HtmlVoid) {
+            self.finish_start_tag();
+            self.tp = None;
+            self.stack.pop();
+            self.after_child = true;
+        }
+    }
+
+    /// Close the final open element, checking that one exists
+    ///
+    /// Returns [Error::UnbalancedClose] if no elements are open.
+    pub fn try_close(&mut self) -> Result<&mut Self, Error> {
+        self.pop_void();
+        if self.stack.is_empty() {
+            return Err(Error::UnbalancedClose);
+        }
+        Ok(self.close())
+    }
+
+    /// Close the final open element, checking its tag
+    ///
+    /// - `tag`: Expected element tag
+    ///
+    /// ```rust
+    /// use hatmil::{Error, Tree, html::Div};
+    ///
+    /// let mut tree = Tree::new();
+    /// let _ = tree.root::<Div>().span();
+    /// assert_eq!(
+    ///     tree.try_close_tag("div").err(),
+    ///     Some(Error::MismatchedClose {
+    ///         expected: "div".into(),
+    ///         actual: "span".into(),
+    ///     }),
+    /// );
+    /// tree.try_close_tag("span").unwrap().try_close_tag("div").unwrap();
+    /// assert_eq!(tree.as_str(), "<div><span></span></div>");
+    /// ```
+    pub fn try_close_tag(&mut self, tag: &str) -> Result<&mut Self, Error> {
+        self.pop_void();
+        match self.stack.last() {
+            None => Err(Error::UnbalancedClose),
+            Some(actual) if actual != tag => Err(Error::MismatchedClose {
+                expected: tag.to_string(),
+                actual: actual.to_string(),
+            }),
+            Some(_) => Ok(self.close()),
+        }
+    }
+
+    /// Close an element, checking that it is the innermost open element
+    ///
+    /// - `depth`: Depth of element to close
+    pub(crate) fn try_close_to(&mut self, depth: usize) -> Result<(), Error> {
+        self.pop_void();
+        match self.stack.len() {
+            len if len < depth => Err(Error::UnbalancedClose),
+            len if len > depth => Err(Error::MismatchedClose {
+                expected: self.stack[depth - 1].to_string(),
+                actual: self.stack[len - 1].to_string(),
+            }),
+            _ => {
+                self.close();
+                Ok(())
+            }
+        }
+    }
+
+    /// Append a fragment
+    ///
+    /// Any open elements in the fragment are closed.
+    pub fn append_fragment(&mut self, frag: &Fragment) -> &mut Self {
+        self.raw(frag.closed_str())
+    }
+
+    /// Append another tree
+    ///
+    /// Any open elements in `other` are closed.  The content is added to the
+    /// innermost open element of this tree.
+    ///
+    /// ```rust
+    /// use hatmil::{Tree, html::{Header, Main}};
+    ///
+    /// let mut header = Tree::new();
+    /// header.root::<Header>().cdata("Top");
+    /// let mut main = Tree::new();
+    /// main.root::<Main>().cdata("Body");
+    /// header.close_all().append(main);
+    /// assert_eq!(header.as_str(), "<header>Top</header><main>Body</main>");
+    /// ```
+    pub fn append(&mut self, other: Tree) -> &mut Self {
+        self.raw(String::from(other))
+    }
+
+    /// Get the document text, with any open elements closed
+    pub(crate) fn closed_str(&self) -> Cow<'_, str> {
+        if self.stack.is_empty() {
+            Cow::Borrowed(&self.doc)
+        } else {
+            Cow::Owned(self.to_string())
+        }
+    }
+
+    /// Close elements to the specified depth
+    pub(crate) fn close_to(&mut self, depth: usize) -> &mut Self {
+        while self.stack.len() >= depth {
+            self.close();
+        }
+        self
+    }
+
+    /// Close the final open element
+    ///
+    /// Add a closing tag (e.g. `</span>`).
+    ///
+    /// ```rust
+    /// use hatmil::{Tree, html::Div};
+    ///
+    /// let mut tree = Tree::new();
+    /// tree.root::<Div>().span().cdata("Text");
+    /// tree.close();
+    /// assert_eq!(tree.as_str(), "<div><span>Text</span>");
+    /// ```
+    pub fn close(&mut self) -> &mut Self {
+        let empty_xml = self.in_start_tag && self.tp == Some(ElemType::Xml);
+        if empty_xml {
+            self.apply_transform();
+            self.in_start_tag = false;
+            self.doc.push_str(" />");
+        } else {
+            self.finish_start_tag();
+        }
+        let tp = self.tp.take();
+        if let Some(tag) = self.stack.pop() {
+            let void = tp == Some(ElemType::HtmlVoid);
+            if !void && !empty_xml {
+                if self.after_child {
+                    self.push_indent();
+                }
+                self.doc.push_str("</");
+                self.doc.push_str(&tag);
+                self.doc.push('>');
+            }
+        }
+        self.in_start_tag = false;
+        self.after_child = true;
+        self
+    }
+
+    /// Close all open elements
+    ///
+    /// The tree can still be used afterwards, for example to add a trailing
+    /// comment.
+    ///
+    /// ```rust
+    /// use hatmil::{Tree, html::Div};
+    ///
+    /// let mut tree = Tree::new();
+    /// tree.root::<Div>().p().cdata("Text");
+    /// tree.close_all().comment("end");
+    /// assert_eq!(tree.as_str(), "<div><p>Text</p></div><!--end-->");
+    /// ```
+    pub fn close_all(&mut self) -> &mut Self {
+        self.close_to(1)
+    }
+}
+
+/// Check if a tag is a valid custom element name
+///
+/// Only lower-case ASCII letters, digits and hyphens are allowed.  It must
+/// start with a letter and contain a hyphen.
+fn is_custom_name(tag: &str) -> bool {
+    tag.starts_with(|c: char| c.is_ascii_lowercase())
+        && tag.contains('-')
+        && tag
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// Check if a `data-*` attribute name suffix is valid
+///
+/// Only lower-case ASCII letters, digits and hyphens are allowed.
+fn is_data_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// Check if an attribute name is valid
+///
+/// Whitespace, control characters, `=`, quotes, `/` and `>` are not allowed.
+fn is_attr_name(attr: &str) -> bool {
+    !attr.is_empty()
+        && !attr.chars().any(|c| {
+            c.is_whitespace()
+                || c.is_control()
+                || matches!(c, '=' | '"' | '\'' | '/' | '>' | '<')
+        })
+}
+
+/// HTML void elements, which have no closing tag
+const VOID_TAGS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta",
+    "source", "track", "wbr",
+];
+
+/// HTML elements with optional end tags
+const OPTIONAL_END_TAGS: &[&str] = &[
+    "body", "colgroup", "dd", "dt", "head", "html", "li", "optgroup", "option",
+    "p", "rp", "rt", "tbody", "td", "tfoot", "th", "thead", "tr",
+];
+
+/// Check raw content for broken markup, panicking on error
+///
+/// - `raw`: Raw content
+/// - `open`: Currently open elements, which may be closed
+/// - `balanced`: Require elements opened in `raw` to be closed, unless their
+///   end tags are optional.  Bare `<` or `>` characters are allowed in text.
+fn check_raw(
+    raw: &str,
+    open: &[Cow<'static, str>],
+    balanced: bool,
+) -> Result<(), Error> {
+    let fail = |problem: &str, at: usize| {
+        let end = raw[at..]
+            .char_indices()
+            .nth(24)
+            .map_or(raw.len(), |(i, _)| at + i);
+        Err(Error::InvalidRaw(format!(
+            "{problem} at byte {at}: {:?}",
+            &raw[at..end]
+        )))
+    };
+    let mut tags: Vec<&str> = Vec::new();
+    let mut pos = 0;
+    while let Some(i) = raw[pos..].find(['<', '>']) {
+        let at = pos + i;
+        let rest = &raw[at..];
+        if rest.starts_with('>') {
+            if !balanced {
+                return fail("has unbalanced '>'", at);
+            }
+            pos = at + 1;
+            continue;
+        }
+        if let Some(com) = rest.strip_prefix("<!--") {
+            match com.find("-->") {
+                Some(end) => pos = at + 4 + end + 3,
+                None => return fail("has unclosed comment", at),
+            }
+            continue;
+        }
+        if balanced
+            && !rest[1..].starts_with(|c: char| c.is_alphabetic() || c == '/')
+        {
+            pos = at + 1;
+            continue;
+        }
+        let end = match tag_end(rest) {
+            Some(end) => at + end,
+            None => return fail("has unbalanced '<'", at),
+        };
+        let tag = &raw[at + 1..end];
+        let (closing, tag) = match tag.strip_prefix('/') {
+            Some(t) => (true, t),
+            None => (false, tag),
+        };
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or("");
+        if name.eq_ignore_ascii_case("script") {
+            return fail("has script tag", at);
+        }
+        if closing {
+            match tags.iter().rposition(|t| t.eq_ignore_ascii_case(name)) {
+                Some(i) => tags.truncate(i),
+                None if open.iter().any(|t| t == name) => (),
+                None => return fail("closes element which is not open", at),
+            }
+        } else if name.starts_with(char::is_alphabetic)
+            && !tag.ends_with('/')
+            && !VOID_TAGS.iter().any(|t| t.eq_ignore_ascii_case(name))
+        {
+            tags.push(name);
+        }
+        pos = end + 1;
+    }
+    if balanced
+        && let Some(tag) = tags.iter().rev().find(|t| {
+            !OPTIONAL_END_TAGS.iter().any(|o| o.eq_ignore_ascii_case(t))
+        })
+    {
+        return Err(Error::InvalidRaw(format!(
+            "has unclosed element: <{tag}>"
+        )));
+    }
+    Ok(())
+}
+
+/// Find the `>` at the end of a tag, skipping quoted attribute values
+fn tag_end(tag: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in tag.char_indices().skip(1) {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            (None, '>') => return Some(i),
+            (None, '<') => return None,
+            _ => (),
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::html::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn div() {
+        let mut tree = Tree::new();
+        let _ = tree.root::<Div>();
+        assert_eq!(tree.to_string(), "<div></div>");
+    }
+
+    #[test]
+    fn boolean() {
+        let mut tree = Tree::new();
+        tree.root::<Div>().id("test").spellcheck(true);
+        assert_eq!(
+            tree.to_string(),
+            "<div id=\"test\" spellcheck=\"true\"></div>"
+        );
+    }
+
+    #[test]
+    fn xml_compatible() {
+        let mut tree = Tree::new();
+        tree.xml_compatible();
+        let mut form = tree.root::<Form>();
+        form.input().disabled();
+        form.button().autofocus().cdata("Go").close();
+        let _ = form.br();
+        assert_eq!(
+            tree.as_str(),
+            "<form><input disabled=\"disabled\" />\
+            <button autofocus=\"autofocus\">Go</button><br"
+        );
+        assert_eq!(
+            tree.to_string(),
+            "<form><input disabled=\"disabled\" />\
+            <button autofocus=\"autofocus\">Go</button><br /></form>"
+        );
+        tree.reset().root::<Div>().img().src("a.png").alt("A");
+        tree.close_all();
+        assert_eq!(tree.as_str(), "<div><img src=\"a.png\" alt=\"A\" /></div>");
+    }
+
+    #[test]
+    fn cdata_truncate() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.p().cdata_truncate("Short", 5, "…").close();
+        div.p().cdata_truncate("Longer <text>", 8, "…").close();
+        div.p().cdata_truncate("Text", 2, " &c.");
+        assert_eq!(
+            tree.to_string(),
+            "<div><p>Short</p><p>Longer &lt;…</p><p>Te &amp;c.</p></div>"
+        );
+    }
+
+    #[test]
+    fn cdata_truncate_clusters() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.p().cdata_truncate("cafe\u{301}s", 4, "…").close();
+        div.p().cdata_truncate("👩\u{200d}🔬 lab", 1, "…");
+        #[cfg(feature = "unicode-segmentation")]
+        assert_eq!(
+            tree.to_string(),
+            "<div><p>cafe\u{301}…</p><p>👩\u{200d}🔬…</p></div>"
+        );
+        #[cfg(not(feature = "unicode-segmentation"))]
+        assert_eq!(tree.to_string(), "<div><p>cafe…</p><p>👩…</p></div>");
+    }
+
+    #[test]
+    fn cdata_fmt() {
+        let (name, n) = ("<Tom & Jerry>", 2);
+        let mut tree = Tree::new();
+        let mut p = tree.root::<P>();
+        p.attr_fmt("title", format_args!("\"{name}\" x{n}"));
+        cdata_fmt!(p, "{name} x{n}");
+        let mut expected = Tree::new();
+        let mut p = expected.root::<P>();
+        p.title(format!("\"{name}\" x{n}"));
+        p.cdata(format!("{name} x{n}"));
+        assert_eq!(String::from(tree), String::from(expected));
+    }
+
+    #[test]
+    fn reset() {
+        let doctype = Doctype::Xhtml1Strict.as_str();
+        let mut tree = Tree::for_doctype(Doctype::Xhtml1Strict);
+        tree.xml_compatible();
+        tree.html().body().input().checked();
+        assert_eq!(
+            tree.to_string(),
+            format!(
+                "{doctype}<html><body><input checked=\"checked\" /></body></html>"
+            )
+        );
+        let cap = tree.doc.capacity();
+        tree.reset();
+        assert!(tree.is_empty());
+        assert_eq!(tree.depth(), 0);
+        assert_eq!(tree.doc.capacity(), cap);
+        let mut html = tree.html();
+        let mut body = html.body();
+        body.p().cdata("Second").close();
+        body.input().checked();
+        assert_eq!(
+            tree.to_string(),
+            format!(
+                "{doctype}<html><body><p>Second</p>\
+                <input checked=\"checked\" /></body></html>"
+            )
+        );
+    }
+
+    #[test]
+    fn if_elem() {
+        let errors = ["Missing name"];
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.if_elem(true, |d| {
+            d.span().cdata("yes").close();
+        })
+        .if_elem(errors.is_empty(), |d| {
+            d.class("ok");
+        });
+        div.if_else_elem(
+            errors.is_empty(),
+            |d| {
+                d.p().cdata("OK").close();
+            },
+            |d| {
+                d.ul().li().cdata(errors[0]).close();
+            },
+        );
+        tree.if_elem(false, |t| {
+            t.comment("never");
+        });
+        assert_eq!(
+            tree.to_string(),
+            "<div><span>yes</span><ul><li>Missing name</li></ul></div>"
+        );
+    }
+
+    #[test]
+    fn write() {
+        let mut tree = Tree::new();
+        let _ = tree.root::<Pre>();
+        write!(tree, "Result: {}", 42).unwrap();
+        writeln!(tree).unwrap();
+        let (a, b) = ("<a>", "<b>");
+        writeln!(tree, "{a} & {b}").unwrap();
+        assert_eq!(
+            tree.to_string(),
+            "<pre>Result: 42\n&lt;a&gt; &amp; &lt;b&gt;\n</pre>"
+        );
+    }
+
+    #[test]
+    fn depth() {
+        let mut tree = Tree::new();
+        assert_eq!(tree.depth(), 0);
+        assert_eq!(tree.current_tag(), None);
+        let mut div = tree.root::<Div>();
+        let _ = div.p();
+        assert_eq!(tree.depth(), 2);
+        assert_eq!(tree.current_tag(), Some("p"));
+        tree.close();
+        assert_eq!(tree.depth(), 1);
+        assert_eq!(tree.current_tag(), Some("div"));
+        tree.try_custom("my-widget").unwrap();
+        assert_eq!(tree.current_tag(), Some("my-widget"));
+    }
+
+    #[test]
+    fn depth_void() {
+        let mut tree = Tree::new();
+        let _ = tree.root::<Div>().br();
+        assert_eq!(tree.depth(), 1);
+        assert_eq!(tree.current_tag(), Some("div"));
+        assert_eq!(tree.current_path(), "div");
+        tree.resume::<Div>().cdata("Text");
+        assert_eq!(String::from(tree), "<div><br>Text</div>");
+    }
+
+    #[test]
+    fn close_all() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.ul().li().cdata("item");
+        let html = tree.to_string();
+        tree.close_all();
+        assert_eq!(tree.depth(), 0);
+        assert_eq!(tree.as_str(), html);
+        tree.close_all();
+        assert_eq!(tree.to_string(), html);
+        assert_eq!(String::from(tree), html);
+    }
+
+    #[test]
+    fn escape_quotes() {
+        let val = "say \"it's\" & go";
+        let mut tree = Tree::new();
+        tree.root::<P>().title(val).cdata(val);
+        assert_eq!(
+            tree.to_string(),
+            "<p title=\"say &quot;it's&quot; &amp; go\">say \"it's\" &amp; go</p>"
+        );
+        let mut tree = Tree::new();
+        tree.escape_quotes(EscapeProfile::Full);
+        let mut p = tree.root::<P>();
+        p.title(val).attr_fmt("data-x", format_args!("{val}"));
+        p.cdata(val);
+        assert_eq!(
+            tree.to_string(),
+            "<p title=\"say &quot;it&#39;s&quot; &amp; go\" \
+            data-x=\"say &quot;it&#39;s&quot; &amp; go\">say \"it's\" &amp; go</p>"
+        );
+    }
+
+    #[test]
+    fn paragraph() {
+        let mut tree = Tree::new();
+        tree.root::<P>().cdata("This is a paragraph");
+        assert_eq!(tree.to_string(), "<p>This is a paragraph</p>");
+    }
+
+    #[test]
+    fn escape_attr() {
+        let mut tree = Tree::new();
+        tree.root::<P>().id("quote\"ampersand&");
+        assert_eq!(
+            tree.to_string(),
+            "<p id=\"quote&quot;ampersand&amp;\"></p>"
+        );
+    }
+
+    #[test]
+    fn escape_cdata() {
+        let mut tree = Tree::new();
+        tree.root::<Em>().cdata("You <&> I");
+        assert_eq!(tree.to_string(), "<em>You &lt;&amp;&gt; I</em>");
+    }
+
+    #[test]
+    fn raw_burger() {
+        let mut tree = Tree::new();
+        tree.root::<Span>().cdata("Raw").raw(" <em>Burger</em>!");
+        assert_eq!(tree.to_string(), "<span>Raw <em>Burger</em>!</span>");
+    }
+
+    #[test]
+    fn void() {
+        let mut tree = Tree::new();
+        tree.root::<Div>().input().r#type("text");
+        assert_eq!(tree.to_string(), "<div><input type=\"text\"></div>");
+    }
+
+    #[test]
+    fn void_sibling() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.input().r#type("text");
+        div.p().cdata("Para").close();
+        let _ = div.br();
+        div.cdata("Text");
+        div.img().src("a.png");
+        div.comment("end");
+        assert_eq!(
+            tree.to_string(),
+            "<div><input type=\"text\"><p>Para</p><br>Text\
+            <img src=\"a.png\"><!--end--></div>"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add id attribute to <div> after content")]
+    fn void_attr_parent() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        let _ = div.br();
+        div.cdata("Text");
+        div.id("x");
+    }
+
+    #[test]
+    fn html() {
+        let mut tree = Tree::new();
+        let mut ol = tree.root::<Ol>();
+        ol.li().class("cat").cdata("nori").close();
+        ol.li().class("cat").cdata("chashu");
+        assert_eq!(
+            tree.to_string(),
+            "<ol><li class=\"cat\">nori</li><li class=\"cat\">chashu</li></ol>"
+        );
+    }
+
+    #[test]
+    fn build_html() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.p().cdata("Paragraph Text").close();
+        div.pre().cdata("Preformatted Text");
+        assert_eq!(
+            tree.to_string(),
+            "<div><p>Paragraph Text</p><pre>Preformatted Text</pre></div>"
+        );
+    }
+
+    #[test]
+    fn html_builder() {
+        let mut tree = Tree::new();
+        let mut html = tree.html();
+        let mut head = html.lang("en").head();
+        head.title_el().cdata("Title!");
+        head.close();
+        html.body().h1().cdata("Header!");
+        assert_eq!(
+            tree.to_string(),
+            "<!DOCTYPE html><html lang=\"en\"><head><title>Title!</title></head><body><h1>Header!</h1></body></html>"
+        );
+    }
+
+    #[test]
+    fn string_from() {
+        let mut tree = Tree::new();
+        let mut html = tree.html();
+        html.head().title_el().cdata("Head").close().close();
+        html.body().cdata("Body");
+        assert_eq!(
+            String::from(tree),
+            "<!DOCTYPE html><html><head><title>Head</title></head><body>Body</body></html>"
+        );
+    }
+
+    #[test]
+    fn comment() {
+        let mut tree = Tree::new();
+        tree.root::<I>().comment("comment");
+        assert_eq!(tree.to_string(), "<i><!--comment--></i>");
+    }
+
+    #[test]
+    fn escape_comment() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.comment("<-->");
+        div.comment("well-known");
+        div.comment("a---b-");
+        assert_eq!(
+            tree.to_string(),
+            "<div><!--&lt;- -&gt;--><!--well-known--><!--a- - -b- --></div>"
+        );
+    }
+
+    #[test]
+    fn escape_comment_strict() {
+        let mut tree = Tree::new();
+        tree.comment_strict("<well-known-->");
+        assert_eq!(
+            tree.to_string(),
+            "<!--&lt;well&hyphen;known&hyphen;&hyphen;&gt;-->"
+        );
+    }
+
+    #[test]
+    fn control_chars() {
+        let mut tree = Tree::new();
+        tree.control_chars(ControlCharPolicy::Strip);
+        let mut div = tree.root::<Div>();
+        div.title("a\0b\tc");
+        div.cdata("x\0y\r\n");
+        div.comment("c\0d");
+        assert_eq!(
+            tree.to_string(),
+            "<div title=\"ab\tc\">xy\r\n<!--cd--></div>"
+        );
+        let mut tree = Tree::new();
+        tree.control_chars(ControlCharPolicy::Replace);
+        let mut div = tree.root::<Div>();
+        div.title("a\0b");
+        div.cdata("\x07\0");
+        div.comment_strict("\x1b");
+        assert_eq!(
+            tree.to_string(),
+            "<div title=\"a\u{FFFD}b\">\u{FFFD}\u{FFFD}<!--\u{FFFD}--></div>"
+        );
+        let mut tree = Tree::new();
+        tree.root::<Div>().title("\0").cdata("\0").comment("\0");
+        assert_eq!(tree.to_string(), "<div title=\"\0\">\0<!--\0--></div>");
+    }
+
+    #[test]
+    fn append() {
+        let mut header = Tree::new();
+        header.html().head().title_el().cdata("T");
+        let mut body = Tree::new();
+        body.root::<Body>().p().cdata("Body");
+        header.close_to(2).append(body);
+        assert_eq!(
+            header.to_string(),
+            "<!DOCTYPE html><html><head><title>T</title></head>\
+            <body><p>Body</p></body></html>"
+        );
+    }
+
+    #[test]
+    fn strict_raw() {
+        let mut tree = Tree::new();
+        tree.strict_raw();
+        let mut div = tree.root::<Div>();
+        div.raw("<b>bold</b><br><!-- a > b --><img src=\"x\" />");
+        div.raw("</div>");
+        assert_eq!(
+            tree.as_str(),
+            "<div><b>bold</b><br><!-- a > b --><img src=\"x\" /></div>"
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "has unbalanced '<' at byte 2")]
+    fn strict_raw_unbalanced() {
+        let mut tree = Tree::new();
+        tree.strict_raw().root::<Div>().raw("a <b");
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "closes element which is not open at byte 3")]
+    fn strict_raw_stray_close() {
+        let mut tree = Tree::new();
+        tree.strict_raw().root::<Div>().raw("<i></b>");
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "has script tag at byte 0")]
+    fn strict_raw_script() {
+        let mut tree = Tree::new();
+        tree.strict_raw()
+            .root::<Div>()
+            .raw("<SCRIPT>alert(1)</SCRIPT>");
+    }
+
+    #[test]
+    fn indent() {
+        let mut tree = Tree::with_indent("\t");
+        let mut div = tree.root::<Div>();
+        div.p().cdata("a").b().cdata("b").close().close();
+        let _ = div.br();
+        div.span().close();
+        assert_eq!(
+            String::from(tree),
+            "<div>\n\t<p>a\n\t\t<b>b</b>\n\t</p>\n\t<br>\n\t<span></span>\n</div>"
+        );
+    }
+
+    #[test]
+    fn indent_text_last() {
+        let mut tree = Tree::with_indent("  ");
+        let mut div = tree.root::<Div>();
+        div.p().cdata("").close();
+        div.p().comment("c").close();
+        div.p().b().close().cdata("a").close();
+        assert_eq!(
+            String::from(tree),
+            "<div>\n  <p></p>\n  <p><!--c--></p>\n  <p>\n    <b></b>a</p>\n</div>"
+        );
+    }
+
+    #[test]
+    fn entity_style() {
+        for (style, expected) in [
+            (
+                EntityStyle::Named,
+                "<div title=\"&quot;a&amp;b&quot;\" class=\"&amp;\">&lt;b&gt; &amp; 1 &lt; 2\
+                <!--&lt;- -&gt;--><!--a&hyphen;b&lt;&gt;-->x&amp;…</div>",
+            ),
+            (
+                EntityStyle::Numeric,
+                "<div title=\"&#34;a&#38;b&#34;\" class=\"&#38;\">&#60;b&#62; &#38; 1 &#60; 2\
+                <!--&#60;- -&#62;--><!--a&#45;b&#60;&#62;-->x&#38;…</div>",
+            ),
+        ] {
+            let mut tree = Tree::new();
+            tree.entity_style(style);
+            let mut div = tree.root::<Div>();
+            div.title("\"a&b\"")
+                .attr_fmt("class", format_args!("{}", '&'));
+            div.cdata("<b> & ");
+            div.cdata_fmt(format_args!("{} < {}", 1, 2));
+            div.comment("<-->").comment_strict("a-b<>");
+            div.cdata_truncate("x&yz", 2, "…");
+            assert_eq!(String::from(tree), expected);
+        }
+    }
+
+    #[test]
+    fn write_doctype() {
+        let mut tree = Tree::for_doctype(Doctype::Xhtml1Strict);
+        tree.xml_declaration().write_doctype();
+        tree.root::<Html>().body().cdata("Text");
+        let html = String::from(tree);
+        let doctype = html.find("<!DOCTYPE html PUBLIC").unwrap();
+        assert!(html.starts_with("<?xml"));
+        assert!(doctype < html.find("<html>").unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "document type declaration must precede")]
+    fn write_doctype_late() {
+        let mut tree = Tree::new();
+        tree.comment("first");
+        tree.write_doctype();
+    }
+
+    #[test]
+    fn fragment_str() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.fragment_str("<strong>bold</strong><br>");
+        div.p().fragment_str("<a href=\"#\">x</a>");
+        assert_eq!(
+            tree.to_string(),
+            "<div><strong>bold</strong><br><p><a href=\"#\">x</a></p></div>"
+        );
+    }
+
+    #[test]
+    fn fragment_str_lenient() {
         let mut tree = Tree::new();
-        tree.root::<Em>().cdata("You <&> I");
-        assert_eq!(tree.to_string(), "<em>You &lt;&amp;&gt; I</em>");
+        let mut div = tree.root::<Div>();
+        div.fragment_str("<a title=\"a<b>c\">x</a> 1 < 2 > 0");
+        div.fragment_str("<ul><li>one<li>two</ul><p>para");
+        assert_eq!(
+            String::from(tree),
+            "<div><a title=\"a<b>c\">x</a> 1 < 2 > 0\
+            <ul><li>one<li>two</ul><p>para</div>"
+        );
     }
 
     #[test]
-    fn raw_burger() {
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "raw content has unclosed element: <em>")]
+    fn fragment_str_unclosed() {
         let mut tree = Tree::new();
-        tree.root::<Span>().cdata("Raw").raw(" <em>Burger</em>!");
-        assert_eq!(tree.to_string(), "<span>Raw <em>Burger</em>!</span>");
+        tree.root::<Div>().fragment_str("<b>x</b><em>y");
     }
 
     #[test]
-    fn void() {
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "closes element which is not open")]
+    fn fragment_str_close_outer() {
         let mut tree = Tree::new();
-        tree.root::<Div>().input().r#type("text");
-        assert_eq!(tree.to_string(), "<div><input type=\"text\"></div>");
+        tree.root::<Div>().fragment_str("x</div>");
     }
 
     #[test]
-    fn html() {
+    fn xml() {
         let mut tree = Tree::new();
-        let mut ol = tree.root::<Ol>();
-        ol.li().class("cat").cdata("nori").close();
-        ol.li().class("cat").cdata("chashu");
-        assert_eq!(
-            tree.to_string(),
-            "<ol><li class=\"cat\">nori</li><li class=\"cat\">chashu</li></ol>"
-        );
+        tree.root::<Link>().rel("stylesheet").close();
+        assert_eq!(tree.to_string(), "<link rel=\"stylesheet\" />");
     }
 
     #[test]
-    fn build_html() {
+    fn close() {
+        let mut tree = Tree::new();
+        tree.root::<Span>().id("gle").close();
+        assert_eq!(tree.to_string(), "<span id=\"gle\"></span>");
+    }
+
+    #[test]
+    fn image() {
+        let mut tree = Tree::new();
+        tree.root::<Img>().width(100).height(50).close();
+        assert_eq!(tree.to_string(), "<img width=\"100\" height=\"50\">");
+    }
+
+    #[test]
+    fn data() {
+        let mut tree = Tree::new();
+        tree.root::<P>().data_("macro", "macrodata");
+        assert_eq!(tree.to_string(), "<p data-macro=\"macrodata\"></p>");
+    }
+
+    #[test]
+    fn data_name() {
         let mut tree = Tree::new();
         let mut div = tree.root::<Div>();
-        div.p().cdata("Paragraph Text").close();
-        div.pre().cdata("Preformatted Text");
+        div.data_("user-id", 42).data_("x2", true);
+        for name in ["", "userId", "user id", "a=b", "é"] {
+            assert_eq!(
+                div.try_data_(name, 1).err(),
+                Some(Error::InvalidAttributeName(format!("data-{name}")))
+            );
+        }
         assert_eq!(
             tree.to_string(),
-            "<div><p>Paragraph Text</p><pre>Preformatted Text</pre></div>"
+            "<div data-user-id=\"42\" data-x2=\"true\"></div>"
         );
     }
 
     #[test]
-    fn html_builder() {
+    #[should_panic(expected = "invalid attribute name: \"data-Id\"")]
+    fn data_uppercase() {
+        let mut tree = Tree::new();
+        tree.root::<Div>().data_("Id", 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add id attribute to <p> after content")]
+    fn attributes() {
+        let mut tree = Tree::new();
+        tree.root::<P>().cdata("character data").id("123");
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add id attribute to <p> after content")]
+    fn attr_after_child() {
+        let mut tree = Tree::new();
+        let mut p = tree.root::<P>();
+        p.span().close();
+        p.id("123");
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add id attribute to <p> after content")]
+    fn attr_after_open_child() {
+        let mut tree = Tree::new();
+        let mut p = tree.root::<P>();
+        let _ = p.span();
+        p.id("123");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "cannot add class attribute to <p> after content"
+    )]
+    fn attr_after_comment() {
+        let mut tree = Tree::new();
+        let mut p = tree.root::<P>();
+        p.comment("comment");
+        p.class("abc");
+    }
+
+    #[test]
+    fn doctype() {
+        for (doctype, decl) in [
+            (Doctype::Html5, "<!DOCTYPE html>"),
+            (
+                Doctype::Xhtml1Strict,
+                "<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Strict//EN\" \
+                \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd\">",
+            ),
+            (
+                Doctype::Xhtml1Transitional,
+                "<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Transitional//EN\" \
+                \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd\">",
+            ),
+            (
+                Doctype::Html4Strict,
+                "<!DOCTYPE HTML PUBLIC \"-//W3C//DTD HTML 4.01//EN\" \
+                \"http://www.w3.org/TR/html4/strict.dtd\">",
+            ),
+            (Doctype::Custom("<!doctype html>"), "<!doctype html>"),
+        ] {
+            let mut tree = Tree::for_doctype(doctype);
+            let _ = tree.html();
+            // calling html again replaces the document
+            let _ = tree.html().body();
+            assert_eq!(
+                String::from(tree),
+                format!("{decl}<html><body></body></html>")
+            );
+        }
+    }
+
+    #[test]
+    fn doctype_xml_declaration() {
+        let mut tree = Tree::for_doctype(Doctype::Xhtml1Strict);
+        tree.xml_declaration();
+        let _ = tree.html();
+        tree.html().xmlns("http://www.w3.org/1999/xhtml");
+        let html = String::from(tree);
+        assert!(html.starts_with(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?><!DOCTYPE html PUBLIC"
+        ));
+        assert_eq!(html.matches("<?xml").count(), 1);
+        assert_eq!(html.matches("<!DOCTYPE").count(), 1);
+        assert!(
+            html.ends_with(
+                "<html xmlns=\"http://www.w3.org/1999/xhtml\"></html>"
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn xml_declaration_after_content() {
+        let mut tree = Tree::new();
+        tree.root::<Div>().close();
+        tree.xml_declaration();
+    }
+
+    #[test]
+    fn as_str() {
+        let mut tree = Tree::new();
+        assert!(tree.is_empty());
+        let mut div = tree.root::<Div>();
+        div.p().cdata("Paragraph").close();
+        let _ = div.span();
+        assert_eq!(tree.as_str(), "<div><p>Paragraph</p><span");
+        assert_eq!(tree.len(), 26);
+        assert!(!tree.is_empty());
+    }
+
+    #[test]
+    fn as_str_start_tag() {
         let mut tree = Tree::new();
+        let _ = tree.root::<Div>();
+        assert_eq!(tree.as_str(), "<div");
+        tree.resume::<Div>().id("a");
+        assert_eq!(tree.as_str(), "<div id=\"a\"");
+        assert!(tree.to_string().starts_with(tree.as_str()));
+        tree.resume::<Div>().cdata("Text");
+        assert_eq!(tree.as_str(), "<div id=\"a\">Text");
+    }
+
+    #[test]
+    fn is_empty() {
+        let mut tree = Tree::default();
+        assert!(tree.is_empty());
+        assert_eq!(tree.len(), 0);
+        let _ = tree.root::<Section>();
+        assert!(!tree.is_empty());
+        assert_eq!(tree.len(), "<section".len());
+    }
+
+    #[test]
+    fn open_tags() {
+        let mut tree = Tree::new();
+        assert_eq!(tree.depth(), 0);
+        assert_eq!(tree.current_path(), "");
         let mut html = tree.html();
-        let mut head = html.lang("en").head();
-        head.title_el().cdata("Title!");
-        head.close();
-        html.body().h1().cdata("Header!");
+        let mut body = html.body();
+        let mut div = body.div();
+        let _ = div.ul();
+        assert_eq!(tree.depth(), 4);
+        assert_eq!(tree.current_path(), "html > body > div > ul");
+        tree.close().close();
+        assert!(tree.open_tags().eq(["html", "body"]));
+        let len = tree.len();
+        tree.close_to(1);
+        assert_eq!(tree.depth(), 0);
+        assert_eq!(tree.len(), len + "</body></html>".len());
+    }
+
+    #[test]
+    fn snapshot() {
+        let mut tree = Tree::new();
+        let mut table = tree.root::<Table>();
+        table.class("grid");
+        let mut thead = table.thead();
+        thead.tr().th().cdata("Name");
+        thead.close();
+        let mut snap = tree.snapshot();
+        for (t, rows) in [(&mut tree, ["a", "b"]), (&mut snap, ["c", "d"])] {
+            let mut table = t.resume::<Table>();
+            let mut tbody = table.tbody();
+            for row in rows {
+                let mut tr = tbody.tr();
+                tr.td().cdata(row);
+                tr.close();
+            }
+        }
         assert_eq!(
-            tree.to_string(),
-            "<!DOCTYPE html><html lang=\"en\"><head><title>Title!</title></head><body><h1>Header!</h1></body></html>"
+            String::from(tree),
+            "<table class=\"grid\"><thead><tr><th>Name</th></tr></thead>\
+            <tbody><tr><td>a</td></tr><tr><td>b</td></tr></tbody></table>"
+        );
+        assert_eq!(
+            String::from(snap),
+            "<table class=\"grid\"><thead><tr><th>Name</th></tr></thead>\
+            <tbody><tr><td>c</td></tr><tr><td>d</td></tr></tbody></table>"
         );
     }
 
     #[test]
-    fn string_from() {
+    #[should_panic(expected = "cannot resume <ol>: innermost is Some(\"ul\")")]
+    fn resume_mismatch() {
+        let mut tree = Tree::new();
+        let _ = tree.root::<Ul>();
+        let _ = tree.resume::<Ol>();
+    }
+
+    /// Card wrapping a tree
+    struct Card(Tree);
+
+    impl fmt::Display for Card {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "<section>")?;
+            self.0.write_to_fmt(f)?;
+            write!(f, "</section>")
+        }
+    }
+
+    #[test]
+    fn write_to_fmt() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.p().cdata("A & B").close();
+        div.img().src("a.png");
+        let html = tree.to_string();
+        assert_eq!(html, "<div><p>A &amp; B</p><img src=\"a.png\"></div>");
+        let card = Card(tree);
+        assert_eq!(card.to_string(), format!("<section>{html}</section>"));
+        assert_eq!(format!("{card:>5}"), format!("<section>{html}</section>"));
+        let mut tree = Tree::new();
+        tree.root::<Link>().rel("icon");
+        let mut out = String::new();
+        tree.write_to_fmt(&mut out).unwrap();
+        assert_eq!(out, tree.to_string());
+        assert_eq!(out, "<link rel=\"icon\" />");
+    }
+
+    #[test]
+    fn as_str_closed() {
+        let mut tree = Tree::new();
+        tree.root::<Div>().input().r#type("text").close().close();
+        assert_eq!(tree.as_str(), "<div><input type=\"text\"></div>");
+        let mut tree = Tree::new();
+        tree.root::<Img>().width(100).height(50).close();
+        assert_eq!(tree.as_str(), "<img width=\"100\" height=\"50\">");
+        let mut tree = Tree::new();
+        tree.root::<Link>().rel("stylesheet").close();
+        assert_eq!(tree.as_str(), "<link rel=\"stylesheet\" />");
+        let mut tree = Tree::new();
+        let mut ol = tree.root::<Ol>();
+        ol.li().class("cat").cdata("nori").close();
+        ol.li().class("cat").cdata("chashu").close();
+        ol.close();
+        assert_eq!(
+            tree.as_str(),
+            "<ol><li class=\"cat\">nori</li><li class=\"cat\">chashu</li></ol>"
+        );
         let mut tree = Tree::new();
         let mut html = tree.html();
         html.head().title_el().cdata("Head").close().close();
-        html.body().cdata("Body");
+        html.body().cdata("Body").close();
+        html.close();
         assert_eq!(
-            String::from(tree),
-            "<!DOCTYPE html><html><head><title>Head</title></head><body>Body</body></html>"
+            tree.as_str(),
+            "<!DOCTYPE html><html><head><title>Head</title></head>\
+            <body>Body</body></html>"
         );
+        assert_eq!(tree.as_str(), tree.to_string());
     }
 
     #[test]
-    fn comment() {
+    fn try_close() {
         let mut tree = Tree::new();
-        tree.root::<I>().comment("comment");
-        assert_eq!(tree.to_string(), "<i><!--comment--></i>");
+        let _ = tree.root::<Div>().p();
+        assert!(tree.try_close().is_ok());
+        assert!(tree.try_close().is_ok());
+        assert_eq!(tree.try_close().err(), Some(Error::UnbalancedClose));
+        assert_eq!(tree.as_str(), "<div><p></p></div>");
     }
 
     #[test]
-    fn escape_comment() {
+    fn try_close_void() {
         let mut tree = Tree::new();
-        tree.comment("<-->");
-        assert_eq!(tree.to_string(), "<!--&lt;&hyphen;&hyphen;&gt;-->");
+        let _ = tree.root::<Div>().br();
+        assert!(tree.try_close_tag("div").is_ok());
+        assert_eq!(tree.as_str(), "<div><br></div>");
+        let _ = tree.reset().root::<P>().input();
+        assert!(tree.try_close().is_ok());
+        assert_eq!(tree.try_close().err(), Some(Error::UnbalancedClose));
+        assert_eq!(tree.as_str(), "<p><input></p>");
     }
 
     #[test]
-    fn xml() {
+    fn try_close_tag() {
         let mut tree = Tree::new();
-        tree.root::<Link>().rel("stylesheet").close();
-        assert_eq!(tree.to_string(), "<link rel=\"stylesheet\" />");
+        tree.root::<Ul>().li().cdata("Item");
+        let err = tree.try_close_tag("ul").err().unwrap();
+        assert_eq!(err.to_string(), "expected to close <ul>, found <li>");
+        tree.try_close_tag("li").unwrap();
+        tree.try_close_tag("ul").unwrap();
+        assert_eq!(
+            tree.try_close_tag("ul").err(),
+            Some(Error::UnbalancedClose)
+        );
+        assert_eq!(tree.as_str(), "<ul><li>Item</li></ul>");
     }
 
     #[test]
-    fn close() {
+    fn try_attr() {
         let mut tree = Tree::new();
-        tree.root::<Span>().id("gle").close();
-        assert_eq!(tree.to_string(), "<span id=\"gle\"></span>");
+        let mut div = tree.root::<Div>();
+        div.try_attr("hx-get", "/items").unwrap();
+        assert_eq!(
+            div.try_attr("foo bar", "x").err(),
+            Some(Error::InvalidAttributeName("foo bar".into()))
+        );
+        div.cdata("Text");
+        assert_eq!(
+            div.try_attr("id", "x").err(),
+            Some(Error::AttributeAfterContent {
+                tag: "div".into(),
+                attr: "id".into(),
+            })
+        );
+        assert_eq!(String::from(tree), "<div hx-get=\"/items\">Text</div>");
     }
 
     #[test]
-    fn image() {
+    fn try_custom() {
         let mut tree = Tree::new();
-        tree.root::<Img>().width(100).height(50).close();
-        assert_eq!(tree.to_string(), "<img width=\"100\" height=\"50\">");
+        assert_eq!(
+            tree.try_custom("Widget").err().map(|e| e.to_string()),
+            Some("invalid custom element name: Widget".into())
+        );
+        let mut div = tree.root::<Div>();
+        assert!(div.try_custom("no_hyphen").is_err());
+        div.try_custom("x-item").unwrap().cdata("Item");
+        assert_eq!(String::from(tree), "<div><x-item>Item</x-item></div>");
     }
 
     #[test]
-    fn data() {
+    fn try_end() {
         let mut tree = Tree::new();
-        tree.root::<P>().data_("macro", "macrodata");
-        assert_eq!(tree.to_string(), "<p data-macro=\"macrodata\"></p>");
+        let mut div = tree.root::<Div>();
+        let _ = div.p();
+        assert_eq!(
+            div.try_end().err(),
+            Some(Error::MismatchedClose {
+                expected: "div".into(),
+                actual: "p".into(),
+            })
+        );
+        tree.close();
+        let mut div = tree.resume::<Div>();
+        let _ = div.br();
+        div.try_end().unwrap();
+        assert_eq!(tree.as_str(), "<div><p></p><br></div>");
     }
 
     #[test]
-    #[should_panic]
-    fn attributes() {
+    fn try_raw_checked() {
         let mut tree = Tree::new();
-        tree.root::<P>().cdata("character data").id("123");
+        let mut div = tree.root::<Div>();
+        div.try_raw_checked("<em>a</em> &amp; </div>").unwrap();
+        for (raw, problem) in [
+            ("a <b", "has unbalanced '<' at byte 2: \"<b\""),
+            ("a > b", "has unbalanced '>' at byte 2: \"> b\""),
+            (
+                "</p>",
+                "closes element which is not open at byte 0: \"</p>\"",
+            ),
+            ("<script>", "has script tag at byte 0: \"<script>\""),
+        ] {
+            let mut tree = Tree::new();
+            assert_eq!(
+                tree.root::<Div>().try_raw_checked(raw).err(),
+                Some(Error::InvalidRaw(problem.into()))
+            );
+            assert_eq!(tree.as_str(), "<div");
+        }
+        assert_eq!(tree.as_str(), "<div><em>a</em> &amp; </div>");
+    }
+
+    #[test]
+    fn depth_exceeded() {
+        let mut tree = Tree::new();
+        tree.max_depth(2);
+        let mut div = tree.root::<Div>();
+        let mut inner = div.div();
+        assert_eq!(
+            inner.try_custom("x-item").err(),
+            Some(Error::DepthExceeded(2))
+        );
+        inner.close();
+        div.try_custom("x-item").unwrap();
+        assert_eq!(
+            String::from(tree),
+            "<div><div></div><x-item></x-item></div>"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "maximum depth of 1 exceeded")]
+    fn depth_exceeded_panic() {
+        let mut tree = Tree::new();
+        tree.max_depth(1);
+        let _ = tree.root::<Div>().span();
+    }
+
+    #[test]
+    fn custom() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        let mut widget = div.custom("my-widget");
+        widget.class("big").data_("size", 3);
+        let tag = String::from("x-item");
+        widget.custom(tag).id("one").cdata("One").close();
+        widget.custom("x-item").id("two").p().cdata("Two");
+        assert_eq!(
+            String::from(tree),
+            "<div><my-widget class=\"big\" data-size=\"3\">\
+            <x-item id=\"one\">One</x-item>\
+            <x-item id=\"two\"><p>Two</p></x-item>\
+            </my-widget></div>"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid custom element name: Widget")]
+    fn custom_invalid() {
+        let mut tree = Tree::new();
+        let _ = tree.custom("Widget");
+    }
+
+    #[test]
+    fn custom_names() {
+        assert!(is_custom_name("my-widget"));
+        assert!(is_custom_name("x-1-2"));
+        assert!(!is_custom_name("widget"));
+        assert!(!is_custom_name("-widget"));
+        assert!(!is_custom_name("my-Widget"));
+        assert!(!is_custom_name("my widget"));
+        assert!(!is_custom_name("my-widget>"));
     }
 
     #[test]
@@ -442,4 +2699,166 @@ mod test {
         tree.root::<Div>().close();
         assert_eq!(String::from(tree), "<div></div><div></div>");
     }
+
+    #[test]
+    fn attr_names() {
+        let mut tree = Tree::new();
+        let _ = tree.root::<Div>();
+        tree.attr(1, "data-id", 5);
+        tree.attr(1, "aria-label", "Label");
+        tree.attr(1, "xml:lang", "en");
+        assert_eq!(
+            String::from(tree),
+            "<div data-id=\"5\" aria-label=\"Label\" xml:lang=\"en\"></div>"
+        );
+        for name in ["", "foo bar", "a=b", "a\"", "a'", "a/", "a>", "a\tb"] {
+            assert!(!is_attr_name(name), "{name:?}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid attribute name: \"data-foo bar\"")]
+    fn attr_name_invalid() {
+        let mut tree = Tree::new();
+        tree.root::<Div>().data_("foo bar", "x");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid attribute name: \"data-x><script\"")]
+    fn attr_name_gt() {
+        let mut tree = Tree::new();
+        tree.root::<Div>().data_("x><script", "x");
+    }
+
+    /// Build a table, counting document reallocations
+    fn table_reallocs(tree: &mut Tree, rows: usize) -> usize {
+        let mut reallocs = 0;
+        let mut capacity = tree.doc.capacity();
+        let mut table = tree.root::<Table>();
+        for row in 0..rows {
+            let mut tr = table.tr();
+            tr.td().cdata(row).close();
+            tr.td().class("name").cdata("Row & column").close();
+            tr.close();
+            if table.tree.doc.capacity() != capacity {
+                capacity = table.tree.doc.capacity();
+                reallocs += 1;
+            }
+        }
+        reallocs
+    }
+
+    #[test]
+    fn reallocs() {
+        for rows in [100, 1000, 10_000] {
+            let mut tree = Tree::new();
+            // capacity doubles, so growth is logarithmic
+            let reallocs = table_reallocs(&mut tree, rows);
+            assert!(
+                reallocs <= rows.ilog2() as usize + 2,
+                "{rows}: {reallocs}"
+            );
+            let mut tree = Tree::new();
+            let tree = tree.estimate_and_reserve(3 * rows, 8);
+            assert_eq!(table_reallocs(tree, rows), 0);
+        }
+    }
+
+    #[test]
+    fn with_capacity() {
+        let mut tree = Tree::with_capacity(1 << 20);
+        let capacity = tree.doc.capacity();
+        assert_eq!(table_reallocs(&mut tree, 10_000), 0);
+        assert_eq!(tree.doc.capacity(), capacity);
+    }
+
+    #[test]
+    fn reserve() {
+        let mut tree = Tree::new();
+        let mut ul = tree.root::<Ul>();
+        ul.reserve(1000);
+        assert!(ul.tree.doc.capacity() >= 1000);
+        ul.li().reserve(4000).cdata("item");
+        assert!(tree.doc.capacity() >= 4000);
+    }
+
+    /// Builder operation
+    #[derive(Clone, Debug)]
+    enum Op {
+        /// Open an element
+        Open(&'static str, ElemType),
+        /// Add character data
+        Text(String),
+        /// Add a comment
+        Comment(String),
+        /// Close an element
+        Close,
+    }
+
+    /// Strategy for builder operations
+    fn op() -> impl Strategy<Value = Op> {
+        prop_oneof![
+            Just(Op::Open("div", ElemType::Html)),
+            Just(Op::Open("span", ElemType::Html)),
+            Just(Op::Open("br", ElemType::HtmlVoid)),
+            Just(Op::Open("circle", ElemType::Xml)),
+            any::<String>().prop_map(Op::Text),
+            any::<String>().prop_map(Op::Comment),
+            Just(Op::Close),
+        ]
+    }
+
+    /// Check that markup is well-formed, returning number of elements
+    fn check_nesting(html: &str) -> usize {
+        let mut stack = Vec::new();
+        let mut count = 0;
+        for part in html.split('<').skip(1) {
+            let (tag, text) = part.split_once('>').expect("unclosed tag");
+            assert!(!text.contains('>'), "unescaped > in {text:?}");
+            if tag.starts_with("!--") {
+                assert!(tag.ends_with("--"), "bad comment {tag:?}");
+                continue;
+            }
+            if let Some(tag) = tag.strip_prefix('/') {
+                assert_eq!(stack.pop(), Some(tag), "mismatched </{tag}>");
+                continue;
+            }
+            count += 1;
+            if tag != "br" && !tag.ends_with(" /") {
+                stack.push(tag);
+            }
+        }
+        assert!(stack.is_empty(), "unclosed {stack:?}");
+        count
+    }
+
+    // Nesting invariant: any sequence of builder calls produces well-formed
+    // markup, with every opened element closed exactly once
+    proptest! {
+        #[test]
+        fn nesting(ops in prop::collection::vec(op(), 0..32)) {
+            let mut tree = Tree::new();
+            let mut opened = 0;
+            for op in ops {
+                match op {
+                    Op::Open(tag, tp) => {
+                        tree.elem(tag, tp);
+                        opened += 1;
+                    }
+                    Op::Text(text) => {
+                        tree.cdata(text);
+                    }
+                    Op::Comment(com) => {
+                        tree.comment(com);
+                    }
+                    Op::Close => {
+                        tree.close();
+                    }
+                }
+            }
+            let html = tree.to_string();
+            prop_assert_eq!(check_nesting(&html), opened);
+            prop_assert_eq!(html, String::from(tree));
+        }
+    }
 }
@@ -0,0 +1,258 @@
+// validate.rs
+//
+// Copyright (C) 2026  Douglas P Lau
+//
+use crate::error::Error;
+use std::collections::HashSet;
+
+/// Document validator (`validate` feature)
+#[derive(Clone, Default)]
+pub(crate) struct Validator {
+    /// Element `id` values
+    ids: HashSet<String>,
+    /// Same-document fragment references
+    fragments: Vec<String>,
+    /// Panic on unresolved fragments
+    panic_unresolved: bool,
+    /// Attribute names of current start tag
+    attrs: Vec<String>,
+}
+
+impl Validator {
+    /// Clear all tracked values
+    pub(crate) fn clear(&mut self) {
+        self.ids.clear();
+        self.fragments.clear();
+    }
+
+    /// Start a new element
+    pub(crate) fn elem(&mut self) {
+        self.attrs.clear();
+    }
+
+    /// Check for a duplicate attribute on the current start tag
+    ///
+    /// Returns [Error::DuplicateAttribute] if `attr` was already added.
+    pub(crate) fn attr_name(
+        &mut self,
+        tag: &str,
+        attr: &str,
+    ) -> Result<(), Error> {
+        if self.attrs.iter().any(|a| a == attr) {
+            return Err(Error::DuplicateAttribute {
+                tag: tag.to_string(),
+                attr: attr.to_string(),
+            });
+        }
+        self.attrs.push(attr.to_string());
+        Ok(())
+    }
+
+    /// Set panic on unresolved fragments mode
+    pub(crate) fn set_panic_unresolved(&mut self, panic: bool) {
+        self.panic_unresolved = panic;
+    }
+
+    /// Check an attribute value
+    pub(crate) fn attr(&mut self, attr: &str, val: &str) {
+        match attr {
+            "id" => {
+                self.ids.insert(val.to_string());
+            }
+            "href" | "xlink:href" => {
+                if let Some(frag) = val.strip_prefix('#')
+                    && !frag.is_empty()
+                {
+                    self.fragments.push(frag.to_string());
+                }
+            }
+            "aria-describedby" | "aria-labelledby" => {
+                for id in val.split_ascii_whitespace() {
+                    self.fragments.push(id.to_string());
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Get fragment references with no matching `id`
+    pub(crate) fn unresolved_fragments(&self) -> Vec<String> {
+        let mut unresolved = Vec::new();
+        for frag in &self.fragments {
+            if !self.ids.contains(frag) && !unresolved.contains(frag) {
+                unresolved.push(frag.clone());
+            }
+        }
+        unresolved
+    }
+
+    /// Check for unresolved fragments, if panic mode is enabled
+    pub(crate) fn check_unresolved(&self) {
+        if self.panic_unresolved {
+            let unresolved = self.unresolved_fragments();
+            if !unresolved.is_empty() {
+                panic!("unresolved fragments: {}", unresolved.join(", "));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::html::Div;
+    use crate::{AriaAttrs, Error, Tree};
+
+    #[test]
+    fn fragments() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.a().href("#top").cdata("Top").close();
+        div.a().href("#missing").cdata("Missing").close();
+        div.a().href("#").cdata("Empty").close();
+        div.a().href("page.html#top").cdata("Other").close();
+        div.p().id("top").cdata("Top");
+        assert_eq!(tree.unresolved_fragments(), ["missing"]);
+    }
+
+    #[test]
+    fn svg_use() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        let mut svg = div.svg();
+        let mut defs = svg.defs();
+        defs.circle().id("dot").r(5).close();
+        defs.close();
+        svg.r#use().href("#dot").close();
+        svg.r#use().href("#dash").close();
+        assert_eq!(tree.unresolved_fragments(), ["dash"]);
+    }
+
+    #[test]
+    fn aria() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.aria_labelledby("title  sub")
+            .aria_describedby("desc sub");
+        div.h1().id("title").cdata("Title");
+        assert_eq!(tree.unresolved_fragments(), ["sub", "desc"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "unresolved fragments: missing")]
+    fn panic_unresolved() {
+        let mut tree = Tree::new();
+        tree.panic_unresolved(true);
+        tree.root::<Div>().a().href("#missing").cdata("Missing");
+        let _html = String::from(tree);
+    }
+
+    #[test]
+    fn resolved() {
+        let mut tree = Tree::new();
+        tree.panic_unresolved(true);
+        let mut div = tree.root::<Div>();
+        div.a().href("#top").cdata("Top").close();
+        div.p().id("top").cdata("Top");
+        assert_eq!(
+            String::from(tree),
+            "<div><a href=\"#top\">Top</a><p id=\"top\">Top</p></div>"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate class attribute on <div>")]
+    fn duplicate_attr() {
+        let mut tree = Tree::new();
+        tree.root::<Div>().class("a").id("b").class("a");
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate autofocus attribute on <p>")]
+    fn duplicate_bool_attr() {
+        let mut tree = Tree::new();
+        tree.root::<Div>().p().autofocus().autofocus();
+    }
+
+    #[test]
+    fn distinct_attrs() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.class("a");
+        div.p().class("b").close();
+        div.p().class("c").close();
+        assert_eq!(
+            String::from(tree),
+            "<div class=\"a\"><p class=\"b\"></p><p class=\"c\"></p></div>"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add p to <div> while <span> is open")]
+    fn wrong_parent() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.span().cdata("Span");
+        div.p().cdata("Para");
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add text to <div> while <p> is open")]
+    fn wrong_parent_text() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.p().cdata("Para");
+        div.cdata("Text");
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add comment to <div> while <p> is open")]
+    fn wrong_parent_comment() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.p().cdata("Para");
+        div.comment("Note");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "cannot add raw content to <div> while <p> is open"
+    )]
+    fn wrong_parent_raw() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.p().cdata("Para");
+        div.raw("<hr>");
+    }
+
+    #[test]
+    fn try_errors() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.try_attr("class", "a").unwrap();
+        assert_eq!(
+            div.try_attr("class", "b").err(),
+            Some(Error::DuplicateAttribute {
+                tag: "div".into(),
+                attr: "class".into(),
+            })
+        );
+        let _ = div.p();
+        let wrong = Error::WrongParent {
+            parent: "div".into(),
+            open: "p".into(),
+            child: "x-item".into(),
+        };
+        assert_eq!(div.try_custom("x-item").err(), Some(wrong));
+        assert!(div.try_raw_checked("text").is_err());
+    }
+
+    #[test]
+    fn void_parent() {
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        let _ = div.br();
+        div.span().cdata("Span").close();
+        div.cdata("Text");
+        assert_eq!(String::from(tree), "<div><br><span>Span</span>Text</div>");
+    }
+}
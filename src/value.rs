@@ -3,6 +3,39 @@
 // Copyright (C) 2025-2026  Douglas P Lau
 //
 use std::borrow::Cow;
+use std::fmt::{self, Write};
+use std::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::Arc;
+#[cfg(feature = "unicode-segmentation")]
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Precision of numbers in typed SVG values, in decimal places
+const SVG_PRECISION: usize = 4;
+
+/// Number in a typed SVG value, formatted like [Value::float]
+pub(crate) struct SvgNum(pub(crate) f64);
+
+impl fmt::Display for SvgNum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(Value::float(self.0, SVG_PRECISION).as_str())
+    }
+}
+
+/// Inline buffer length, in bytes (enough for any integer)
+const INLINE_LEN: usize = 40;
+
+/// Inline buffer for short formatted values
+struct InlineBuf {
+    /// UTF-8 bytes
+    buf: [u8; INLINE_LEN],
+    /// Length in bytes
+    len: usize,
+}
 
 /// Character iterator
 enum CharIter<'a> {
@@ -10,22 +43,433 @@ enum CharIter<'a> {
     Borrowed(&'a str),
     /// Owned string
     Owned(String),
+    /// Atomically reference-counted string
+    Arc(Arc<str>),
+    /// Reference-counted string
+    Rc(Rc<str>),
+    /// Inline buffer
+    Inline(InlineBuf),
+    /// No value
+    None,
+}
+
+impl fmt::Write for InlineBuf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > INLINE_LEN {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl InlineBuf {
+    /// Get buffer as a string slice
+    fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.buf[..self.len]).expect("invalid UTF-8")
+    }
+}
+
+impl CharIter<'_> {
+    /// Format a value into an inline buffer, or an owned string if too long
+    fn inline<T: fmt::Display>(v: T) -> Self {
+        let mut buf = InlineBuf {
+            buf: [0; INLINE_LEN],
+            len: 0,
+        };
+        match write!(buf, "{v}") {
+            Ok(()) => CharIter::Inline(buf),
+            Err(_) => CharIter::Owned(v.to_string()),
+        }
+    }
+}
+
+/// Get entity for a character in character data
+fn cdata_entity(c: char) -> Option<&'static str> {
+    match c {
+        '&' => Some("&amp;"),
+        '<' => Some("&lt;"),
+        '>' => Some("&gt;"),
+        _ => None,
+    }
+}
+
+/// Get entity for a character in an attribute value
+fn attr_entity(c: char) -> Option<&'static str> {
+    match c {
+        '&' => Some("&amp;"),
+        '"' => Some("&quot;"),
+        _ => None,
+    }
+}
+
+/// Get entity for a character in an attribute value, including `'`
+fn attr_full_entity(c: char) -> Option<&'static str> {
+    match c {
+        '\'' => Some("&#39;"),
+        _ => attr_entity(c),
+    }
+}
+
+/// Get entity for a character in a strict comment
+fn comment_strict_entity(c: char) -> Option<&'static str> {
+    match c {
+        '-' => Some("&hyphen;"),
+        '<' => Some("&lt;"),
+        '>' => Some("&gt;"),
+        _ => None,
+    }
+}
+
+/// Push text, replacing characters with entities
+///
+/// Spans with no replaced characters are pushed as whole slices.  Replaced
+/// characters must be ASCII, so text is scanned by byte.
+fn push_escaped(
+    out: &mut String,
+    text: &str,
+    entity: fn(char) -> Option<&'static str>,
+    style: EntityStyle,
+) {
+    let escaped = |b: &u8| b.is_ascii() && entity(char::from(*b)).is_some();
+    let mut rest = text;
+    while let Some(i) = rest.bytes().position(|b| escaped(&b)) {
+        let (clean, tail) = rest.split_at(i);
+        out.push_str(clean);
+        if let Some(ent) = entity(char::from(tail.as_bytes()[0])) {
+            out.push_str(style.entity(ent));
+        }
+        rest = &tail[1..];
+    }
+    // fast path: clean text is pushed with no further scanning
+    out.push_str(rest);
+}
+
+/// Attribute value escaping profile
+///
+/// ```rust
+/// use hatmil::{EscapeProfile, Tree, html::P};
+///
+/// let mut tree = Tree::new();
+/// tree.escape_quotes(EscapeProfile::Full);
+/// tree.root::<P>().title("\"It's\"");
+/// assert_eq!(String::from(tree), "<p title=\"&quot;It&#39;s&quot;\"></p>");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EscapeProfile {
+    /// Escape `&` and `"`
+    #[default]
+    Minimal,
+    /// Escape `&`, `"` and `'`
+    Full,
+}
+
+impl EscapeProfile {
+    /// Get entity function for attribute values
+    fn attr_entity(self) -> fn(char) -> Option<&'static str> {
+        match self {
+            EscapeProfile::Minimal => attr_entity,
+            EscapeProfile::Full => attr_full_entity,
+        }
+    }
+}
+
+/// Character reference style
+///
+/// ```rust
+/// use hatmil::{EntityStyle, Tree, html::P};
+///
+/// let mut tree = Tree::new();
+/// tree.entity_style(EntityStyle::Numeric);
+/// tree.root::<P>().title("\"a&b\"").cdata("<b>");
+/// assert_eq!(
+///     String::from(tree),
+///     "<p title=\"&#34;a&#38;b&#34;\">&#60;b&#62;</p>",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EntityStyle {
+    /// Named references, such as `&amp;`
+    #[default]
+    Named,
+    /// Numeric references, such as `&#38;`
+    Numeric,
+}
+
+impl EntityStyle {
+    /// Get a named entity in this style
+    fn entity(self, ent: &'static str) -> &'static str {
+        match (self, ent) {
+            (EntityStyle::Named, _) => ent,
+            (_, "&amp;") => "&#38;",
+            (_, "&lt;") => "&#60;",
+            (_, "&gt;") => "&#62;",
+            (_, "&quot;") => "&#34;",
+            (_, "&hyphen;") => "&#45;",
+            _ => ent,
+        }
+    }
+}
+
+/// Policy for C0 control characters in text and attribute values
+///
+/// Tab, line feed and carriage return are always kept.
+///
+/// ```rust
+/// use hatmil::{ControlCharPolicy, Tree, html::P};
+///
+/// let mut tree = Tree::new();
+/// tree.control_chars(ControlCharPolicy::Replace);
+/// tree.root::<P>().cdata("a\0b");
+/// assert_eq!(String::from(tree), "<p>a\u{FFFD}b</p>");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ControlCharPolicy {
+    /// Keep control characters unchanged
+    #[default]
+    Keep,
+    /// Remove control characters
+    Strip,
+    /// Replace control characters with `U+FFFD`
+    Replace,
+}
+
+/// Check if a character is a disallowed control character
+fn is_disallowed_control(c: char) -> bool {
+    c < ' ' && !matches!(c, '\t' | '\n' | '\r')
+}
+
+impl ControlCharPolicy {
+    /// Apply policy to the end of a string, starting at a byte index
+    pub(crate) fn apply(self, out: &mut String, start: usize) {
+        if self == ControlCharPolicy::Keep
+            || !out[start..].contains(is_disallowed_control)
+        {
+            return;
+        }
+        let tail = out.split_off(start);
+        for c in tail.chars() {
+            if !is_disallowed_control(c) {
+                out.push(c);
+            } else if self == ControlCharPolicy::Replace {
+                out.push(char::REPLACEMENT_CHARACTER);
+            }
+        }
+    }
+}
+
+/// Writer which escapes character data
+pub(crate) struct CdataWriter<'a>(
+    pub(crate) &'a mut String,
+    pub(crate) EntityStyle,
+);
+
+/// Writer which escapes attribute values
+pub(crate) struct AttrWriter<'a>(
+    pub(crate) &'a mut String,
+    pub(crate) EscapeProfile,
+    pub(crate) EntityStyle,
+);
+
+impl fmt::Write for CdataWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        push_escaped(self.0, s, cdata_entity, self.1);
+        Ok(())
+    }
+}
+
+impl fmt::Write for AttrWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        push_escaped(self.0, s, self.1.attr_entity(), self.2);
+        Ok(())
+    }
 }
 
 /// A value of an attribute or text content
+///
+/// Values can be converted from strings, numbers and other types.  An
+/// attribute with a `None` value is skipped:
+///
+/// ```rust
+/// use hatmil::{Tree, html::Td};
+/// use std::borrow::Cow;
+/// use std::sync::Arc;
+///
+/// let mut tree = Tree::new();
+/// let mut td = tree.root::<Td>();
+/// td.colspan(2).rowspan(1_u8).title(String::from("a&b"));
+/// td.cdata('x').cdata(1.5).cdata(true).cdata(Cow::from("|"));
+/// td.cdata(Arc::<str>::from("<arc>")).id(None::<&str>);
+/// assert_eq!(
+///     String::from(tree),
+///     "<td colspan=\"2\" rowspan=\"1\" title=\"a&amp;b\">x1.5true|&lt;arc&gt;</td>",
+/// );
+/// ```
 pub struct Value<'a> {
     iter: CharIter<'a>,
 }
 
-impl Value<'_> {
-    /// Get character iterator
-    pub(crate) fn chars(&'_ self) -> impl Iterator<Item = char> {
+impl<'a> Value<'a> {
+    /// Create a value from any type implementing [Display]
+    ///
+    /// The value is written directly into an owned buffer.
+    ///
+    /// ```rust
+    /// # use hatmil::{Tree, Value, html::Div};
+    /// # use std::fmt;
+    /// struct Px(u32);
+    ///
+    /// impl fmt::Display for Px {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    ///         write!(f, "{}px", self.0)
+    ///     }
+    /// }
+    ///
+    /// let mut tree = Tree::new();
+    /// tree.root::<Div>().title(Value::from_display(Px(100)));
+    /// assert_eq!(String::from(tree), "<div title=\"100px\"></div>");
+    /// ```
+    ///
+    /// [Display]: fmt::Display
+    pub fn from_display<T: fmt::Display>(t: T) -> Self {
+        let mut s = String::new();
+        write!(s, "{t}").expect("Display implementation returned an error");
+        Value {
+            iter: CharIter::Owned(s),
+        }
+    }
+
+    /// Create a borrowed value from any string reference
+    ///
+    /// ```rust
+    /// # use hatmil::{Tree, Value, html::P};
+    /// let name = Box::<str>::from("boxed");
+    /// let mut tree = Tree::new();
+    /// tree.root::<P>().cdata(Value::str(&name));
+    /// assert_eq!(String::from(tree), "<p>boxed</p>");
+    /// ```
+    pub fn str<S: AsRef<str> + ?Sized>(s: &'a S) -> Self {
+        Value {
+            iter: CharIter::Borrowed(s.as_ref()),
+        }
+    }
+
+    /// Create a value by joining items with a separator
+    ///
+    /// ```rust
+    /// # use hatmil::{Tree, Value, svg::Animate};
+    /// let mut tree = Tree::new();
+    /// tree.root::<Animate>().key_times(Value::joined(["0", "0.5", "1"], ";"));
+    /// assert_eq!(String::from(tree), "<animate keyTimes=\"0;0.5;1\" />");
+    /// ```
+    pub fn joined<I>(items: I, sep: &str) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Value<'a>>,
+    {
+        let mut s = String::new();
+        for (i, item) in items.into_iter().enumerate() {
+            if i > 0 {
+                s.push_str(sep);
+            }
+            s.push_str(item.into().as_str());
+        }
+        Value {
+            iter: CharIter::Owned(s),
+        }
+    }
+
+    /// Create a URL value from a filesystem path
+    ///
+    /// Backslashes are converted to forward slashes.  Non-UTF-8 components
+    /// become `U+FFFD` replacement characters.
+    ///
+    /// ```rust
+    /// # use hatmil::{Tree, Value, html::A};
+    /// # use std::path::Path;
+    /// let mut tree = Tree::new();
+    /// tree.root::<A>().href(Value::from_path_url(Path::new("docs\\a b.html")));
+    /// assert_eq!(String::from(tree), "<a href=\"docs/a b.html\"></a>");
+    /// ```
+    pub fn from_path_url(path: &Path) -> Self {
+        Value {
+            iter: CharIter::Owned(path.to_string_lossy().replace('\\', "/")),
+        }
+    }
+
+    /// Create a floating-point value with fixed precision
+    ///
+    /// - `precision`: Precision in decimal places
+    ///
+    /// Trailing zeros are trimmed.  A tuple of `(f64, usize)` can also be
+    /// converted.
+    ///
+    /// ```rust
+    /// # use hatmil::{Tree, Value, svg::Rect};
+    /// let mut tree = Tree::new();
+    /// tree.root::<Rect>()
+    ///     .width(Value::float(0.1 + 0.2, 3))
+    ///     .height((12.5, 0));
+    /// assert_eq!(String::from(tree), "<rect width=\"0.3\" height=\"12\" />");
+    /// ```
+    pub fn float(v: f64, precision: usize) -> Self {
+        let mut s = String::new();
+        write!(s, "{v:.0$}", precision).unwrap();
+        if precision > 0 {
+            while s.ends_with('0') {
+                s.pop();
+            }
+            if s.ends_with('.') {
+                s.pop();
+            }
+        }
+        // negative values which round to zero
+        if s == "-0" {
+            s.remove(0);
+        }
+        Value {
+            iter: CharIter::Owned(s),
+        }
+    }
+
+    /// Get length hint, in bytes
+    ///
+    /// This is the length before any characters are replaced with entities.
+    pub fn len_hint(&self) -> usize {
         match &self.iter {
-            CharIter::Borrowed(s) => s.chars(),
-            CharIter::Owned(s) => s.chars(),
+            CharIter::Borrowed(s) => s.len(),
+            CharIter::Owned(s) => s.len(),
+            CharIter::Arc(s) => s.len(),
+            CharIter::Rc(s) => s.len(),
+            CharIter::Inline(b) => b.len,
+            CharIter::None => 0,
         }
     }
 
+    /// Get value as a string slice
+    pub(crate) fn as_str(&self) -> &str {
+        match &self.iter {
+            CharIter::Borrowed(s) => s,
+            CharIter::Owned(s) => s,
+            CharIter::Arc(s) => s,
+            CharIter::Rc(s) => s,
+            CharIter::Inline(b) => b.as_str(),
+            CharIter::None => "",
+        }
+    }
+
+    /// Get character iterator
+    pub(crate) fn chars(&'_ self) -> impl Iterator<Item = char> {
+        self.as_str().chars()
+    }
+
+    /// Check if value is `None`
+    pub(crate) fn is_none(&self) -> bool {
+        matches!(self.iter, CharIter::None)
+    }
+
     /// Encode value to an attribute
     ///
     /// These characters will be replaced with entities:
@@ -33,13 +477,21 @@ impl Value<'_> {
     /// - `&` ⇨ `&amp;`
     /// - `"` ⇨ `&quot;`
     pub fn encode_attr(&'_ self, attr: &mut String) {
-        for c in self.chars() {
-            match c {
-                '&' => attr.push_str("&amp;"),
-                '"' => attr.push_str("&quot;"),
-                _ => attr.push(c),
-            }
-        }
+        self.encode_attr_profile(
+            attr,
+            EscapeProfile::Minimal,
+            EntityStyle::Named,
+        );
+    }
+
+    /// Encode value to an attribute, with an escaping profile and style
+    pub(crate) fn encode_attr_profile(
+        &'_ self,
+        attr: &mut String,
+        profile: EscapeProfile,
+        style: EntityStyle,
+    ) {
+        push_escaped(attr, self.as_str(), profile.attr_entity(), style);
     }
 
     /// Encode value to character data
@@ -50,14 +502,16 @@ impl Value<'_> {
     /// - `<` ⇨ `&lt;`
     /// - `>` ⇨ `&gt;`
     pub fn encode_cdata(&'_ self, cdata: &mut String) {
-        for c in self.chars() {
-            match c {
-                '&' => cdata.push_str("&amp;"),
-                '<' => cdata.push_str("&lt;"),
-                '>' => cdata.push_str("&gt;"),
-                _ => cdata.push(c),
-            }
-        }
+        self.encode_cdata_style(cdata, EntityStyle::Named);
+    }
+
+    /// Encode value to character data, with an entity style
+    pub(crate) fn encode_cdata_style(
+        &'_ self,
+        cdata: &mut String,
+        style: EntityStyle,
+    ) {
+        push_escaped(cdata, self.as_str(), cdata_entity, style);
     }
 
     /// Encode value to character data with length limit
@@ -68,33 +522,146 @@ impl Value<'_> {
     /// - `<` ⇨ `&lt;`
     /// - `>` ⇨ `&gt;`
     pub fn encode_cdata_len(&'_ self, cdata: &mut String, len: usize) {
-        for c in self.chars().take(len) {
-            match c {
-                '&' => cdata.push_str("&amp;"),
-                '<' => cdata.push_str("&lt;"),
-                '>' => cdata.push_str("&gt;"),
-                _ => cdata.push(c),
+        self.encode_cdata_len_style(cdata, len, EntityStyle::Named);
+    }
+
+    /// Encode value to character data with length limit and entity style
+    pub(crate) fn encode_cdata_len_style(
+        &'_ self,
+        cdata: &mut String,
+        len: usize,
+        style: EntityStyle,
+    ) {
+        let text = self.as_str();
+        let end = text.char_indices().nth(len).map_or(text.len(), |(i, _)| i);
+        push_escaped(cdata, &text[..end], cdata_entity, style);
+    }
+
+    /// Encode value to character data, truncated with a suffix
+    ///
+    /// If the value is longer than `len`, it is truncated and `suffix` is
+    /// appended.  With the `unicode-segmentation` feature, `len` counts
+    /// extended grapheme clusters; otherwise it counts characters.
+    ///
+    /// Characters are replaced with entities as in [encode_cdata].
+    ///
+    /// [encode_cdata]: Self::encode_cdata
+    pub fn encode_cdata_truncate(
+        &'_ self,
+        cdata: &mut String,
+        len: usize,
+        suffix: &str,
+    ) {
+        self.encode_cdata_truncate_style(
+            cdata,
+            len,
+            suffix,
+            EntityStyle::Named,
+        );
+    }
+
+    /// Encode value to character data, truncated with a suffix and style
+    pub(crate) fn encode_cdata_truncate_style(
+        &'_ self,
+        cdata: &mut String,
+        len: usize,
+        suffix: &str,
+        style: EntityStyle,
+    ) {
+        let text = self.as_str();
+        #[cfg(feature = "unicode-segmentation")]
+        let end = text.grapheme_indices(true).nth(len).map(|(i, _)| i);
+        #[cfg(not(feature = "unicode-segmentation"))]
+        let end = text.char_indices().nth(len).map(|(i, _)| i);
+        match end {
+            Some(end) => {
+                push_escaped(cdata, &text[..end], cdata_entity, style);
+                push_escaped(cdata, suffix, cdata_entity, style);
             }
+            None => push_escaped(cdata, text, cdata_entity, style),
         }
     }
 
+    /// Encode value to raw text of a `script` or `style` element
+    ///
+    /// `</tag` (case-insensitive) is replaced with `<\/tag`, and `<!--`
+    /// with `<\!--`.
+    pub(crate) fn encode_raw_text(&'_ self, out: &mut String, tag: &str) {
+        let mut rest = self.as_str();
+        while let Some(i) = rest.find('<') {
+            let (clean, tail) = rest.split_at(i);
+            out.push_str(clean);
+            let after = &tail[1..];
+            if let Some(name) = after.strip_prefix('/')
+                && name
+                    .get(..tag.len())
+                    .is_some_and(|n| n.eq_ignore_ascii_case(tag))
+            {
+                out.push_str("<\\/");
+                rest = name;
+            } else if let Some(com) = after.strip_prefix("!--") {
+                out.push_str("<\\!--");
+                rest = com;
+            } else {
+                out.push('<');
+                rest = after;
+            }
+        }
+        out.push_str(rest);
+    }
+
     /// Encode value to a comment
     ///
-    /// These characters will be replaced with entities:
+    /// Single hyphens are unchanged, but a space is inserted between
+    /// consecutive hyphens and after a trailing hyphen, so the comment cannot
+    /// be closed early.  These characters will be replaced with entities:
     ///
-    /// - `-` ⇨ `&hyphen;`
     /// - `<` ⇨ `&lt;`
     /// - `>` ⇨ `&gt;`
     pub fn encode_comment(&'_ self, comment: &mut String) {
+        self.encode_comment_style(comment, EntityStyle::Named);
+    }
+
+    /// Encode value to a comment, with an entity style
+    pub(crate) fn encode_comment_style(
+        &'_ self,
+        comment: &mut String,
+        style: EntityStyle,
+    ) {
+        let mut hyphen = false;
         for c in self.chars() {
             match c {
-                '-' => comment.push_str("&hyphen;"),
-                '<' => comment.push_str("&lt;"),
-                '>' => comment.push_str("&gt;"),
+                '-' if hyphen => comment.push_str(" -"),
+                '<' => comment.push_str(style.entity("&lt;")),
+                '>' => comment.push_str(style.entity("&gt;")),
                 _ => comment.push(c),
             }
+            hyphen = c == '-';
+        }
+        if hyphen {
+            comment.push(' ');
         }
     }
+
+    /// Encode value to a comment, replacing all hyphens
+    ///
+    /// These characters will be replaced with entities:
+    ///
+    /// - `-` ⇨ `&hyphen;`
+    /// - `<` ⇨ `&lt;`
+    /// - `>` ⇨ `&gt;`
+    pub fn encode_comment_strict(&'_ self, comment: &mut String) {
+        self.encode_comment_strict_style(comment, EntityStyle::Named);
+    }
+
+    /// Encode value to a comment, replacing all hyphens, with an entity style
+    pub(crate) fn encode_comment_strict_style(
+        &'_ self,
+        comment: &mut String,
+        style: EntityStyle,
+    ) {
+        push_escaped(comment, self.as_str(), comment_strict_entity, style);
+    }
 }
 
 impl<'c> From<&'c str> for Value<'c> {
@@ -134,10 +701,78 @@ impl<'c> From<Cow<'c, str>> for Value<'c> {
     }
 }
 
+impl From<Arc<str>> for Value<'_> {
+    fn from(v: Arc<str>) -> Self {
+        Value {
+            iter: CharIter::Arc(v),
+        }
+    }
+}
+
+impl From<Rc<str>> for Value<'_> {
+    fn from(v: Rc<str>) -> Self {
+        Value {
+            iter: CharIter::Rc(v),
+        }
+    }
+}
+
+/// Non-UTF-8 components become `U+FFFD` replacement characters
+impl<'c> From<&'c Path> for Value<'c> {
+    fn from(v: &'c Path) -> Self {
+        Value::from(v.to_string_lossy())
+    }
+}
+
+/// Non-UTF-8 components become `U+FFFD` replacement characters
+impl From<PathBuf> for Value<'_> {
+    fn from(v: PathBuf) -> Self {
+        let s = match v.into_os_string().into_string() {
+            Ok(s) => s,
+            Err(os) => os.to_string_lossy().into_owned(),
+        };
+        Value {
+            iter: CharIter::Owned(s),
+        }
+    }
+}
+
+impl<'c> From<&'c Arc<str>> for Value<'c> {
+    fn from(v: &'c Arc<str>) -> Self {
+        Value {
+            iter: CharIter::Borrowed(v),
+        }
+    }
+}
+
+impl<'c> From<&'c Rc<str>> for Value<'c> {
+    fn from(v: &'c Rc<str>) -> Self {
+        Value {
+            iter: CharIter::Borrowed(v),
+        }
+    }
+}
+
+impl From<&[&str]> for Value<'_> {
+    fn from(v: &[&str]) -> Self {
+        Value {
+            iter: CharIter::Owned(v.join(" ")),
+        }
+    }
+}
+
+impl From<&[String]> for Value<'_> {
+    fn from(v: &[String]) -> Self {
+        Value {
+            iter: CharIter::Owned(v.join(" ")),
+        }
+    }
+}
+
 impl From<char> for Value<'_> {
     fn from(v: char) -> Self {
         Value {
-            iter: CharIter::Owned(v.to_string()),
+            iter: CharIter::inline(v),
         }
     }
 }
@@ -145,7 +780,7 @@ impl From<char> for Value<'_> {
 impl From<bool> for Value<'_> {
     fn from(v: bool) -> Self {
         Value {
-            iter: CharIter::Owned(v.to_string()),
+            iter: CharIter::inline(v),
         }
     }
 }
@@ -153,7 +788,7 @@ impl From<bool> for Value<'_> {
 impl From<i8> for Value<'_> {
     fn from(v: i8) -> Self {
         Value {
-            iter: CharIter::Owned(v.to_string()),
+            iter: CharIter::inline(v),
         }
     }
 }
@@ -161,7 +796,7 @@ impl From<i8> for Value<'_> {
 impl From<u8> for Value<'_> {
     fn from(v: u8) -> Self {
         Value {
-            iter: CharIter::Owned(v.to_string()),
+            iter: CharIter::inline(v),
         }
     }
 }
@@ -169,7 +804,7 @@ impl From<u8> for Value<'_> {
 impl From<i16> for Value<'_> {
     fn from(v: i16) -> Self {
         Value {
-            iter: CharIter::Owned(v.to_string()),
+            iter: CharIter::inline(v),
         }
     }
 }
@@ -177,7 +812,7 @@ impl From<i16> for Value<'_> {
 impl From<u16> for Value<'_> {
     fn from(v: u16) -> Self {
         Value {
-            iter: CharIter::Owned(v.to_string()),
+            iter: CharIter::inline(v),
         }
     }
 }
@@ -185,7 +820,7 @@ impl From<u16> for Value<'_> {
 impl From<i32> for Value<'_> {
     fn from(v: i32) -> Self {
         Value {
-            iter: CharIter::Owned(v.to_string()),
+            iter: CharIter::inline(v),
         }
     }
 }
@@ -193,7 +828,7 @@ impl From<i32> for Value<'_> {
 impl From<u32> for Value<'_> {
     fn from(v: u32) -> Self {
         Value {
-            iter: CharIter::Owned(v.to_string()),
+            iter: CharIter::inline(v),
         }
     }
 }
@@ -201,7 +836,7 @@ impl From<u32> for Value<'_> {
 impl From<i64> for Value<'_> {
     fn from(v: i64) -> Self {
         Value {
-            iter: CharIter::Owned(v.to_string()),
+            iter: CharIter::inline(v),
         }
     }
 }
@@ -209,7 +844,7 @@ impl From<i64> for Value<'_> {
 impl From<u64> for Value<'_> {
     fn from(v: u64) -> Self {
         Value {
-            iter: CharIter::Owned(v.to_string()),
+            iter: CharIter::inline(v),
         }
     }
 }
@@ -217,7 +852,7 @@ impl From<u64> for Value<'_> {
 impl From<i128> for Value<'_> {
     fn from(v: i128) -> Self {
         Value {
-            iter: CharIter::Owned(v.to_string()),
+            iter: CharIter::inline(v),
         }
     }
 }
@@ -225,7 +860,7 @@ impl From<i128> for Value<'_> {
 impl From<u128> for Value<'_> {
     fn from(v: u128) -> Self {
         Value {
-            iter: CharIter::Owned(v.to_string()),
+            iter: CharIter::inline(v),
         }
     }
 }
@@ -233,7 +868,7 @@ impl From<u128> for Value<'_> {
 impl From<isize> for Value<'_> {
     fn from(v: isize) -> Self {
         Value {
-            iter: CharIter::Owned(v.to_string()),
+            iter: CharIter::inline(v),
         }
     }
 }
@@ -241,7 +876,103 @@ impl From<isize> for Value<'_> {
 impl From<usize> for Value<'_> {
     fn from(v: usize) -> Self {
         Value {
-            iter: CharIter::Owned(v.to_string()),
+            iter: CharIter::inline(v),
+        }
+    }
+}
+
+impl From<NonZeroI8> for Value<'_> {
+    fn from(v: NonZeroI8) -> Self {
+        Value {
+            iter: CharIter::inline(v.get()),
+        }
+    }
+}
+
+impl From<NonZeroU8> for Value<'_> {
+    fn from(v: NonZeroU8) -> Self {
+        Value {
+            iter: CharIter::inline(v.get()),
+        }
+    }
+}
+
+impl From<NonZeroI16> for Value<'_> {
+    fn from(v: NonZeroI16) -> Self {
+        Value {
+            iter: CharIter::inline(v.get()),
+        }
+    }
+}
+
+impl From<NonZeroU16> for Value<'_> {
+    fn from(v: NonZeroU16) -> Self {
+        Value {
+            iter: CharIter::inline(v.get()),
+        }
+    }
+}
+
+impl From<NonZeroI32> for Value<'_> {
+    fn from(v: NonZeroI32) -> Self {
+        Value {
+            iter: CharIter::inline(v.get()),
+        }
+    }
+}
+
+impl From<NonZeroU32> for Value<'_> {
+    fn from(v: NonZeroU32) -> Self {
+        Value {
+            iter: CharIter::inline(v.get()),
+        }
+    }
+}
+
+impl From<NonZeroI64> for Value<'_> {
+    fn from(v: NonZeroI64) -> Self {
+        Value {
+            iter: CharIter::inline(v.get()),
+        }
+    }
+}
+
+impl From<NonZeroU64> for Value<'_> {
+    fn from(v: NonZeroU64) -> Self {
+        Value {
+            iter: CharIter::inline(v.get()),
+        }
+    }
+}
+
+impl From<NonZeroI128> for Value<'_> {
+    fn from(v: NonZeroI128) -> Self {
+        Value {
+            iter: CharIter::inline(v.get()),
+        }
+    }
+}
+
+impl From<NonZeroU128> for Value<'_> {
+    fn from(v: NonZeroU128) -> Self {
+        Value {
+            iter: CharIter::inline(v.get()),
+        }
+    }
+}
+
+impl From<NonZeroIsize> for Value<'_> {
+    fn from(v: NonZeroIsize) -> Self {
+        Value {
+            iter: CharIter::inline(v.get()),
+        }
+    }
+}
+
+impl From<NonZeroUsize> for Value<'_> {
+    fn from(v: NonZeroUsize) -> Self {
+        Value {
+            iter: CharIter::inline(v.get()),
         }
     }
 }
@@ -249,15 +980,652 @@ impl From<usize> for Value<'_> {
 impl From<f32> for Value<'_> {
     fn from(v: f32) -> Self {
         Value {
-            iter: CharIter::Owned(v.to_string()),
+            iter: CharIter::inline(v),
         }
     }
 }
 
 impl From<f64> for Value<'_> {
     fn from(v: f64) -> Self {
+        Value {
+            iter: CharIter::inline(v),
+        }
+    }
+}
+
+impl<'a, T> From<Option<T>> for Value<'a>
+where
+    T: Into<Value<'a>>,
+{
+    fn from(v: Option<T>) -> Self {
+        match v {
+            Some(v) => v.into(),
+            None => Value {
+                iter: CharIter::None,
+            },
+        }
+    }
+}
+
+impl From<(f64, usize)> for Value<'_> {
+    fn from((v, precision): (f64, usize)) -> Self {
+        Value::float(v, precision)
+    }
+}
+
+#[cfg(feature = "url")]
+impl<'c> From<&'c url::Url> for Value<'c> {
+    fn from(v: &'c url::Url) -> Self {
+        Value {
+            iter: CharIter::Borrowed(v.as_str()),
+        }
+    }
+}
+
+#[cfg(feature = "url")]
+impl From<url::Url> for Value<'_> {
+    fn from(v: url::Url) -> Self {
+        Value {
+            iter: CharIter::Owned(v.into()),
+        }
+    }
+}
+
+/// Date or date and time (`chrono` or `time` feature)
+///
+/// Values are ISO 8601 strings, as used by `datetime` attributes:
+///
+/// - `chrono`: [DateTime], [NaiveDateTime] and [NaiveDate]
+/// - `time`: [OffsetDateTime], [PrimitiveDateTime] and [Date]
+///
+/// Other types, such as `NaiveTime` or `time::Time`, are not supported.
+///
+/// [DateTime]: https://docs.rs/chrono/latest/chrono/struct.DateTime.html
+/// [NaiveDateTime]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveDateTime.html
+/// [NaiveDate]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveDate.html
+/// [OffsetDateTime]: https://docs.rs/time/latest/time/struct.OffsetDateTime.html
+/// [PrimitiveDateTime]: https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
+/// [Date]: https://docs.rs/time/latest/time/struct.Date.html
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub trait DateTimeIso: Into<Value<'static>> {}
+
+/// RFC 3339 date and time, e.g. `2026-10-17T12:30:00Z`
+#[cfg(feature = "chrono")]
+impl<Tz> From<chrono::DateTime<Tz>> for Value<'_>
+where
+    Tz: chrono::TimeZone,
+    Tz::Offset: fmt::Display,
+{
+    fn from(v: chrono::DateTime<Tz>) -> Self {
+        Value {
+            iter: CharIter::Owned(
+                v.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true),
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz> DateTimeIso for chrono::DateTime<Tz>
+where
+    Tz: chrono::TimeZone,
+    Tz::Offset: fmt::Display,
+{
+}
+
+/// Local date and time, e.g. `2026-10-17T12:30:00`
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDateTime> for Value<'_> {
+    fn from(v: chrono::NaiveDateTime) -> Self {
+        use chrono::Timelike;
+        let fmt = if v.nanosecond() == 0 {
+            "%Y-%m-%dT%H:%M:%S"
+        } else {
+            "%Y-%m-%dT%H:%M:%S%.3f"
+        };
+        Value {
+            iter: CharIter::Owned(v.format(fmt).to_string()),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl DateTimeIso for chrono::NaiveDateTime {}
+
+/// ISO 8601 date, e.g. `2026-10-17`
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for Value<'_> {
+    fn from(v: chrono::NaiveDate) -> Self {
         Value {
             iter: CharIter::Owned(v.to_string()),
         }
     }
 }
+
+#[cfg(feature = "chrono")]
+impl DateTimeIso for chrono::NaiveDate {}
+
+/// RFC 3339 date and time, e.g. `2026-10-17T12:30:00Z`
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for Value<'_> {
+    fn from(v: time::OffsetDateTime) -> Self {
+        let s = v
+            .format(&time::format_description::well_known::Rfc3339)
+            .unwrap_or_else(|_| v.to_string());
+        Value {
+            iter: CharIter::Owned(s),
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl DateTimeIso for time::OffsetDateTime {}
+
+/// Local date and time, e.g. `2026-10-17T12:30:00`
+#[cfg(feature = "time")]
+impl From<time::PrimitiveDateTime> for Value<'_> {
+    fn from(v: time::PrimitiveDateTime) -> Self {
+        let mut s = format!(
+            "{}T{:02}:{:02}:{:02}",
+            v.date(),
+            v.hour(),
+            v.minute(),
+            v.second()
+        );
+        if v.nanosecond() != 0 {
+            write!(s, ".{:03}", v.millisecond()).unwrap();
+        }
+        Value {
+            iter: CharIter::Owned(s),
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl DateTimeIso for time::PrimitiveDateTime {}
+
+/// ISO 8601 date, e.g. `2026-10-17`
+#[cfg(feature = "time")]
+impl From<time::Date> for Value<'_> {
+    fn from(v: time::Date) -> Self {
+        Value {
+            iter: CharIter::Owned(v.to_string()),
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl DateTimeIso for time::Date {}
+
+/// Serialize a value as JSON, escaped for an inline script
+///
+/// `<`, `>` and `&` can only appear within JSON strings, so they are written
+/// as `\u` escapes, along with U+2028 and U+2029.
+#[cfg(feature = "serde_json")]
+pub(crate) fn encode_json<T>(value: &T) -> serde_json::Result<String>
+where
+    T: serde::Serialize + ?Sized,
+{
+    let json = serde_json::to_string(value)?;
+    let mut out = String::with_capacity(json.len());
+    for c in json.chars() {
+        match c {
+            '<' => out.push_str("\\u003c"),
+            '>' => out.push_str("\\u003e"),
+            '&' => out.push_str("\\u0026"),
+            '\u{2028}' => out.push_str("\\u2028"),
+            '\u{2029}' => out.push_str("\\u2029"),
+            _ => out.push(c),
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Tree;
+    use crate::html::{A, Div, P, TextArea};
+    use crate::svg::Rect;
+    use proptest::prelude::*;
+
+    #[test]
+    fn arc() {
+        let href: Arc<str> = Arc::from("https://example.com/?a=1&b=2");
+        let mut tree = Tree::new();
+        tree.root::<A>().href(Arc::clone(&href)).cdata(href);
+        assert_eq!(
+            String::from(tree),
+            "<a href=\"https://example.com/?a=1&amp;b=2\">\
+            https://example.com/?a=1&amp;b=2</a>"
+        );
+    }
+
+    #[test]
+    fn borrowed() {
+        let arc: Arc<str> = Arc::from("arc");
+        let rc: Rc<str> = Rc::from("rc");
+        let boxed: Box<str> = Box::from("boxed");
+        for val in [Value::from(&arc), Value::from(&rc), Value::str(&boxed)] {
+            assert!(matches!(val.iter, CharIter::Borrowed(_)));
+        }
+        let mut tree = Tree::new();
+        tree.root::<A>()
+            .href(&arc)
+            .title(&rc)
+            .cdata(Value::str(&boxed));
+        assert_eq!(
+            String::from(tree),
+            "<a href=\"arc\" title=\"rc\">boxed</a>"
+        );
+        assert_eq!(Arc::strong_count(&arc), 1);
+    }
+
+    #[test]
+    fn paths() {
+        let unix = Path::new("/srv/my files/a&b.png");
+        let windows = PathBuf::from("C:\\My Files\\img.png");
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.img().src(unix).alt(windows.clone());
+        div.a().href(Value::from_path_url(&windows));
+        assert_eq!(
+            String::from(tree),
+            "<div><img src=\"/srv/my files/a&amp;b.png\" \
+            alt=\"C:\\My Files\\img.png\">\
+            <a href=\"C:/My Files/img.png\"></a></div>"
+        );
+    }
+
+    #[cfg(feature = "url")]
+    #[test]
+    fn url() {
+        let url = url::Url::parse("https://example.com/a b?x=1&y=2").unwrap();
+        assert!(matches!(Value::from(&url).iter, CharIter::Borrowed(_)));
+        assert!(matches!(Value::from(url.clone()).iter, CharIter::Owned(_)));
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.a().href(&url).close();
+        div.img().src_url(&url).unwrap();
+        div.a().href_url(&url).unwrap().cdata(url);
+        assert_eq!(
+            String::from(tree),
+            "<div><a href=\"https://example.com/a%20b?x=1&amp;y=2\"></a>\
+            <img src=\"https://example.com/a%20b?x=1&amp;y=2\">\
+            <a href=\"https://example.com/a%20b?x=1&amp;y=2\">\
+            https://example.com/a%20b?x=1&amp;y=2</a></div>"
+        );
+    }
+
+    #[cfg(feature = "url")]
+    #[test]
+    fn url_javascript() {
+        let url = url::Url::parse("JavaScript:alert(1)").unwrap();
+        let mut tree = Tree::new();
+        let mut a = tree.root::<A>();
+        assert_eq!(
+            a.href_url(&url).err(),
+            Some(crate::Error::DisallowedUrlScheme("javascript".into()))
+        );
+        a.href(&url);
+        assert_eq!(String::from(tree), "<a href=\"javascript:alert(1)\"></a>");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        use chrono::{NaiveDate, TimeZone, Utc};
+        let dt = Utc.with_ymd_and_hms(2026, 10, 17, 12, 30, 5).unwrap();
+        let date = NaiveDate::from_ymd_opt(2026, 1, 2).unwrap();
+        let mut tree = Tree::new();
+        let mut p = tree.root::<P>();
+        p.time().datetime(dt).cdata("Noon").close();
+        p.del().datetime(date);
+        assert_eq!(
+            String::from(tree),
+            "<p><time datetime=\"2026-10-17T12:30:05Z\">Noon</time>\
+            <del datetime=\"2026-01-02\"></del></p>"
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_iso() {
+        use chrono::{FixedOffset, NaiveDate, TimeZone};
+        let date = NaiveDate::from_ymd_opt(2026, 1, 2).unwrap();
+        let local = date.and_hms_milli_opt(8, 5, 0, 250).unwrap();
+        let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+        let dt = offset.with_ymd_and_hms(2026, 1, 2, 8, 5, 0).unwrap();
+        let mut tree = Tree::new();
+        let mut p = tree.root::<P>();
+        p.time().datetime_iso(date).close();
+        p.time().datetime_iso(local).close();
+        p.time()
+            .datetime_iso(date.and_hms_opt(8, 5, 0).unwrap())
+            .close();
+        p.time().datetime_iso(dt).close();
+        assert_eq!(
+            String::from(tree),
+            "<p><time datetime=\"2026-01-02\"></time>\
+            <time datetime=\"2026-01-02T08:05:00.250\"></time>\
+            <time datetime=\"2026-01-02T08:05:00\"></time>\
+            <time datetime=\"2026-01-02T08:05:00+02:00\"></time></p>"
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time() {
+        use time::{Date, Month, OffsetDateTime, Time, UtcOffset};
+        let date = Date::from_calendar_date(2026, Month::October, 17).unwrap();
+        let dt = OffsetDateTime::new_in_offset(
+            date,
+            Time::from_hms_milli(8, 0, 0, 250).unwrap(),
+            UtcOffset::from_hms(-6, 0, 0).unwrap(),
+        );
+        let mut tree = Tree::new();
+        let mut p = tree.root::<P>();
+        p.ins().datetime(dt).close();
+        p.time().datetime(date);
+        assert_eq!(
+            String::from(tree),
+            "<p><ins datetime=\"2026-10-17T08:00:00.25-06:00\"></ins>\
+            <time datetime=\"2026-10-17\"></time></p>"
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_iso() {
+        use time::{Date, Month, PrimitiveDateTime, Time};
+        let date = Date::from_calendar_date(2026, Month::October, 17).unwrap();
+        let local = PrimitiveDateTime::new(
+            date,
+            Time::from_hms_milli(8, 0, 0, 250).unwrap(),
+        );
+        let mut tree = Tree::new();
+        let mut p = tree.root::<P>();
+        p.time().datetime_iso(date).close();
+        p.time().datetime_iso(local).close();
+        p.time().datetime_iso(local.replace_millisecond(0).unwrap());
+        assert_eq!(
+            String::from(tree),
+            "<p><time datetime=\"2026-10-17\"></time>\
+            <time datetime=\"2026-10-17T08:00:00.250\"></time>\
+            <time datetime=\"2026-10-17T08:00:00\"></time></p>"
+        );
+    }
+
+    #[test]
+    fn rc() {
+        let title: Rc<str> = Rc::from("\"Shared\"");
+        let mut tree = Tree::new();
+        tree.root::<A>().title(Rc::clone(&title)).cdata(title);
+        assert_eq!(
+            String::from(tree),
+            "<a title=\"&quot;Shared&quot;\">\"Shared\"</a>"
+        );
+    }
+
+    #[test]
+    fn option() {
+        let website: Option<String> = None;
+        let title = Some("Home");
+        let mut tree = Tree::new();
+        tree.root::<A>()
+            .href(website.as_deref())
+            .title(title)
+            .id(None::<&str>)
+            .tabindex(Some(1))
+            .cdata(None::<&str>)
+            .cdata(Some("Link"));
+        assert_eq!(
+            String::from(tree),
+            "<a title=\"Home\" tabindex=\"1\">Link</a>"
+        );
+    }
+
+    #[test]
+    fn slices() {
+        let rel: &[&str] = &["noopener", "noreferrer"];
+        let ping = [String::from("/a?x=1&y=2"), String::from("/\"b\"")];
+        let empty: &[&str] = &[];
+        let mut tree = Tree::new();
+        tree.root::<A>()
+            .rel(rel)
+            .ping(&ping[..])
+            .class(empty)
+            .cdata(&["one", "two"][..]);
+        assert_eq!(
+            String::from(tree),
+            "<a rel=\"noopener noreferrer\" \
+            ping=\"/a?x=1&amp;y=2 /&quot;b&quot;\" class=\"\">one two</a>"
+        );
+    }
+
+    #[test]
+    fn float() {
+        let mut tree = Tree::new();
+        tree.root::<Rect>()
+            .height(0.1_f64 + 0.2)
+            .x(Value::float(0.1 + 0.2, 2))
+            .y((-1.25, 1))
+            .width((100.0, 3));
+        assert_eq!(
+            String::from(tree),
+            "<rect height=\"0.30000000000000004\" x=\"0.3\" y=\"-1.2\" \
+            width=\"100\" />"
+        );
+    }
+
+    #[test]
+    fn float_negative_zero() {
+        assert_eq!(Value::float(-0.001, 2).as_str(), "0");
+        assert_eq!(Value::float(-0.4, 0).as_str(), "0");
+        assert_eq!(Value::float(-0.0, 1).as_str(), "0");
+        assert_eq!(Value::float(-0.006, 2).as_str(), "-0.01");
+    }
+
+    #[test]
+    fn inline() {
+        let vals = [
+            Value::from(u128::MAX),
+            Value::from(i128::MIN),
+            Value::from(-1.5e-7_f64),
+            Value::from(f32::MAX),
+            Value::from('ü'),
+            Value::from(false),
+        ];
+        for val in &vals {
+            assert!(matches!(val.iter, CharIter::Inline(_)));
+        }
+        let strs: Vec<String> =
+            vals.iter().map(|v| v.chars().collect()).collect();
+        assert_eq!(
+            strs,
+            [
+                u128::MAX.to_string(),
+                i128::MIN.to_string(),
+                (-1.5e-7_f64).to_string(),
+                f32::MAX.to_string(),
+                'ü'.to_string(),
+                false.to_string(),
+            ]
+        );
+        let big = Value::from(f64::MAX);
+        assert!(matches!(big.iter, CharIter::Owned(_)));
+        assert_eq!(big.chars().collect::<String>(), f64::MAX.to_string());
+    }
+
+    #[test]
+    fn joined() {
+        let srcset = ["a.png 1x", "a\"2\".png 2x"];
+        let mut tree = Tree::new();
+        let mut div = tree.root::<Div>();
+        div.img()
+            .srcset(Value::joined(srcset, ", "))
+            .sizes(Value::joined([100], ","))
+            .alt(Value::joined(Vec::<&str>::new(), ","));
+        div.input().accept(Value::joined([".png", ".jpg"], ","));
+        assert_eq!(
+            String::from(tree),
+            "<div><img srcset=\"a.png 1x, a&quot;2&quot;.png 2x\" sizes=\"100\" \
+            alt=\"\"><input accept=\".png,.jpg\"></div>"
+        );
+    }
+
+    #[test]
+    fn non_zero() {
+        let mut tree = Tree::new();
+        let mut ta = tree.root::<TextArea>();
+        ta.cols(NonZeroU32::new(3).unwrap());
+        ta.rows(NonZeroUsize::new(12).unwrap());
+        ta.tabindex(NonZeroI8::new(-1).unwrap());
+        assert_eq!(
+            String::from(tree),
+            "<textarea cols=\"3\" rows=\"12\" tabindex=\"-1\"></textarea>"
+        );
+        assert_eq!(
+            Value::from(NonZeroU128::MAX).as_str(),
+            u128::MAX.to_string()
+        );
+    }
+
+    #[test]
+    fn len_hint() {
+        assert_eq!(Value::from("a&b").len_hint(), 3);
+        assert_eq!(Value::from(12345).len_hint(), 5);
+        assert_eq!(Value::from(Arc::<str>::from("ü")).len_hint(), 2);
+    }
+
+    /// Replace character references with characters
+    fn unescape(text: &str) -> String {
+        text.replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&hyphen;", "-")
+            .replace("&amp;", "&")
+    }
+
+    /// Escape text one character at a time
+    fn escape_chars(
+        text: &str,
+        entity: fn(char) -> Option<&'static str>,
+    ) -> String {
+        let mut out = String::new();
+        for c in text.chars() {
+            match entity(c) {
+                Some(ent) => out.push_str(ent),
+                None => out.push(c),
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn clean_text() {
+        let text = "It was the best of times — naïve café";
+        let mut out = String::from("<p>");
+        Value::from(text).encode_cdata(&mut out);
+        Value::from(text).encode_attr(&mut out);
+        assert_eq!(out, format!("<p>{text}{text}"));
+        let mut out = String::new();
+        Value::from("é<ü>&\"").encode_cdata(&mut out);
+        Value::from("é<ü>&\"").encode_attr(&mut out);
+        assert_eq!(out, "é&lt;ü&gt;&amp;\"é<ü>&amp;&quot;");
+    }
+
+    // Escaping invariants:
+    // - character data contains no `<` or `>`
+    // - attribute values contain no `"`
+    // - comments contain no `--`, `<` or `>`, and do not end with `-`
+    // - all escaped text (except for spaces in comments) can be unescaped to
+    //   the original
+    proptest! {
+        #[test]
+        fn cdata_escaping(text in any::<String>()) {
+            let mut cdata = String::new();
+            Value::from(&text).encode_cdata(&mut cdata);
+            prop_assert!(!cdata.contains(['<', '>']));
+            prop_assert_eq!(unescape(&cdata), text);
+        }
+
+        #[test]
+        fn chunked_escaping(text in "[a-c&<>\"ü-]{0,64}") {
+            for entity in [
+                cdata_entity,
+                attr_entity,
+                attr_full_entity,
+                comment_strict_entity,
+            ] {
+                let mut out = String::new();
+                push_escaped(&mut out, &text, entity, EntityStyle::Named);
+                prop_assert_eq!(out, escape_chars(&text, entity));
+            }
+        }
+
+        #[test]
+        fn byte_scan_escaping(text in any::<String>()) {
+            for entity in [cdata_entity, attr_full_entity] {
+                let mut out = String::new();
+                push_escaped(&mut out, &text, entity, EntityStyle::Named);
+                prop_assert_eq!(out, escape_chars(&text, entity));
+            }
+        }
+
+        #[test]
+        fn attr_escaping(text in any::<String>()) {
+            let mut tree = Tree::new();
+            tree.root::<P>().title(&text);
+            let html = String::from(tree);
+            let val = html
+                .strip_prefix("<p title=\"")
+                .and_then(|h| h.strip_suffix("\"></p>"))
+                .unwrap();
+            prop_assert!(!val.contains('"'));
+            prop_assert_eq!(unescape(val), text);
+        }
+
+        #[test]
+        fn comment_escaping(text in any::<String>()) {
+            let mut com = String::new();
+            Value::from(&text).encode_comment(&mut com);
+            prop_assert!(!com.contains("--"));
+            prop_assert!(!com.contains(['<', '>']));
+            prop_assert!(!com.ends_with('-'));
+            prop_assert_eq!(
+                unescape(&com).replace(' ', ""),
+                text.replace(' ', "")
+            );
+        }
+
+        #[test]
+        fn comment_strict_escaping(text in any::<String>()) {
+            let mut com = String::new();
+            Value::from(&text).encode_comment_strict(&mut com);
+            prop_assert!(!com.contains(['-', '<', '>']));
+            prop_assert_eq!(unescape(&com), text);
+        }
+
+        #[test]
+        fn cdata_len_escaping(text in any::<String>(), len in 0..32_usize) {
+            let mut cdata = String::new();
+            Value::from(&text).encode_cdata_len(&mut cdata, len);
+            prop_assert!(!cdata.contains(['<', '>']));
+            let expected: String = text.chars().take(len).collect();
+            prop_assert_eq!(unescape(&cdata), expected);
+        }
+
+        #[test]
+        fn cdata_truncate_escaping(text in any::<String>(), len in 0..32_usize) {
+            let mut cdata = String::new();
+            Value::from(&text).encode_cdata_truncate(&mut cdata, len, "<…>");
+            prop_assert!(!cdata.contains(['<', '>']));
+            let plain = unescape(&cdata);
+            match plain.strip_suffix("<…>") {
+                Some(head) => prop_assert!(text.starts_with(head)),
+                None => prop_assert_eq!(plain, text),
+            }
+        }
+    }
+}
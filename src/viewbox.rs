@@ -0,0 +1,99 @@
+// viewbox.rs
+//
+// Copyright (C) 2026  Douglas P Lau
+//
+use crate::value::{SvgNum, Value};
+use std::fmt;
+
+/// SVG [viewBox] attribute
+///
+/// Numbers are written with up to 4 decimal places, trimming trailing zeros.
+///
+/// ```rust
+/// use hatmil::{Tree, ViewBox, svg::Svg};
+///
+/// let mut tree = Tree::new();
+/// tree.root::<Svg>().view_box(ViewBox::fit(100.0, 50.0));
+/// assert_eq!(String::from(tree), "<svg viewBox=\"0 0 100 50\" />");
+/// ```
+///
+/// [viewBox]: https://developer.mozilla.org/en-US/docs/Web/SVG/Reference/Attribute/viewBox
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ViewBox {
+    /// Minimum X coordinate
+    pub min_x: f64,
+    /// Minimum Y coordinate
+    pub min_y: f64,
+    /// Width
+    pub width: f64,
+    /// Height
+    pub height: f64,
+}
+
+impl fmt::Display for ViewBox {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            SvgNum(self.min_x),
+            SvgNum(self.min_y),
+            SvgNum(self.width),
+            SvgNum(self.height)
+        )
+    }
+}
+
+impl From<ViewBox> for Value<'_> {
+    fn from(vb: ViewBox) -> Self {
+        Value::from(vb.to_string())
+    }
+}
+
+impl ViewBox {
+    /// Create a new view box
+    pub fn new(min_x: f64, min_y: f64, width: f64, height: f64) -> Self {
+        ViewBox {
+            min_x,
+            min_y,
+            width,
+            height,
+        }
+    }
+
+    /// Create a view box starting at the origin
+    pub fn fit(width: f64, height: f64) -> Self {
+        ViewBox::new(0.0, 0.0, width, height)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Tree;
+    use crate::svg::Svg;
+
+    #[test]
+    fn view_box() {
+        assert_eq!(ViewBox::fit(100.0, 50.0).to_string(), "0 0 100 50");
+        assert_eq!(
+            ViewBox::new(-5.0, 2.5, 10.0, 20.0).to_string(),
+            "-5 2.5 10 20"
+        );
+        assert_eq!(
+            ViewBox::new(-0.00001, 0.1 + 0.2, 200.0 / 3.0, 1e-9).to_string(),
+            "0 0.3 66.6667 0"
+        );
+    }
+
+    #[test]
+    fn svg() {
+        let mut tree = Tree::new();
+        let mut svg = tree.root::<Svg>();
+        svg.view_box(ViewBox::new(-1.0, -1.0, 2.0, 2.0));
+        svg.symbol().view_box("0 0 10 10");
+        assert_eq!(
+            String::from(tree),
+            "<svg viewBox=\"-1 -1 2 2\"><symbol viewBox=\"0 0 10 10\" /></svg>"
+        );
+    }
+}
@@ -0,0 +1,52 @@
+fn card() -> Tree {
+    let mut tree = Tree::new();
+    {
+        let mut div = tree.root::<html::Div>();
+        div.class("card").id("c1");
+        div.h2().cdata("Fish & chips").close();
+        {
+            let mut p = div.p();
+            p.cdata("Some ");
+            p.em().cdata("text").close();
+            p.cdata(" here.");
+            p.close();
+        }
+        {
+            let mut ul = div.ul();
+            ul.li().cdata("One").close();
+            ul.li().data_("n", "2").cdata("Two").close();
+            ul.close();
+        }
+        div.img().src("a.png").alt("A").close();
+        div.comment(" note ");
+        {
+            let mut svg = div.svg();
+            svg.view_box("0 0 10 10");
+            {
+                let mut a = svg.a();
+                a.href("#c1");
+                a.circle().cx("5").cy("5").r("4").close();
+                a.close();
+            }
+            svg.close();
+        }
+        {
+            let mut table = div.table();
+            {
+                let mut tr = table.tr();
+                {
+                    let mut th = tr.th();
+                    th.abbr("Qty");
+                    th.cdata("Quantity ");
+                    th.abbr_el().title("approximate").cdata("approx.").close();
+                    th.close();
+                }
+                tr.close();
+            }
+            table.close();
+        }
+        div.button().r#type("button").aria_expanded(false).disabled().cdata("Go").close();
+        div.close();
+    }
+    tree
+}
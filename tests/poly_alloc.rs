@@ -0,0 +1,74 @@
+// poly_alloc.rs
+//
+// Copyright (C) 2026  Douglas P Lau
+//
+use hatmil::svg::Polygon;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Allocator which counts allocations
+struct Counting;
+
+static ALLOCS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+        new_size: usize,
+    ) -> *mut u8 {
+        ALLOCS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+const POLYS: u32 = 100;
+const POINTS: u32 = 32;
+
+/// Count allocations made by a closure
+fn count_allocs(f: impl FnOnce()) -> usize {
+    let before = ALLOCS.load(Ordering::Relaxed);
+    f();
+    ALLOCS.load(Ordering::Relaxed) - before
+}
+
+#[test]
+fn clear_reuses_allocation() {
+    let fresh = count_allocs(|| {
+        for i in 0..POLYS {
+            let mut points = Polygon::point_builder();
+            for j in 0..POINTS {
+                points.add((i * j, i + j));
+            }
+            black_box(&points);
+        }
+    });
+    let reused = count_allocs(|| {
+        let mut points = Polygon::point_builder();
+        for i in 0..POLYS {
+            points.clear();
+            for j in 0..POINTS {
+                points.add((i * j, i + j));
+            }
+            black_box(&points);
+        }
+    });
+    // fresh builders grow their string for every polygon
+    assert!(fresh >= POLYS as usize, "fresh: {fresh}");
+    // a cleared builder only grows until it fits the largest polygon
+    assert!(reused < 20, "reused: {reused}");
+}